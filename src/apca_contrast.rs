@@ -0,0 +1,196 @@
+use crate::rgb_color::RgbColor;
+
+/// Polarity of a text/background pair
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum ApcaPolarity {
+    /// Dark text on light background, positive Lc
+    DarkOnLight,
+    /// Light text on dark background, negative Lc
+    LightOnDark,
+}
+
+// APCA 0.0.98G-4g constants
+const MAIN_TRC: f32 = 2.4;
+const R_COEF: f32 = 0.2126729;
+const G_COEF: f32 = 0.7151522;
+const B_COEF: f32 = 0.0721750;
+
+const NORM_BG: f32 = 0.56;
+const NORM_TXT: f32 = 0.57;
+const REV_TXT: f32 = 0.62;
+const REV_BG: f32 = 0.65;
+
+const BLK_THRS: f32 = 0.022;
+const BLK_CLMP: f32 = 1.414;
+const SCALE_BOW: f32 = 1.14;
+const SCALE_WOB: f32 = 1.14;
+const LO_BOW_OFFSET: f32 = 0.027;
+const LO_WOB_OFFSET: f32 = 0.027;
+const DELTA_Y_MIN: f32 = 0.0005;
+const LO_CLIP: f32 = 0.1;
+
+/// APCA font lookup: minimum font size (px) for each Lc row,
+/// by font weight 100..=900; 777 means non-text only, 999 prohibited
+const FONT_LOOKUP: [(f32, [f32; 9]); 25] = [
+    (0.0, [999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0]),
+    (10.0, [999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0]),
+    (15.0, [777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0]),
+    (20.0, [777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0]),
+    (25.0, [777.0, 777.0, 777.0, 120.0, 120.0, 108.0, 96.0, 96.0, 96.0]),
+    (30.0, [777.0, 777.0, 120.0, 108.0, 108.0, 96.0, 72.0, 72.0, 72.0]),
+    (35.0, [777.0, 120.0, 108.0, 96.0, 72.0, 60.0, 48.0, 48.0, 48.0]),
+    (40.0, [120.0, 108.0, 96.0, 60.0, 48.0, 42.0, 32.0, 32.0, 32.0]),
+    (45.0, [108.0, 96.0, 72.0, 42.0, 32.0, 28.0, 24.0, 24.0, 24.0]),
+    (50.0, [96.0, 72.0, 60.0, 32.0, 28.0, 24.0, 21.0, 21.0, 21.0]),
+    (55.0, [80.0, 60.0, 48.0, 28.0, 24.0, 21.0, 18.0, 18.0, 18.0]),
+    (60.0, [72.0, 48.0, 42.0, 24.0, 21.0, 18.0, 16.0, 16.0, 18.0]),
+    (65.0, [68.0, 46.0, 32.0, 21.75, 19.0, 17.0, 15.0, 16.0, 18.0]),
+    (70.0, [64.0, 44.0, 28.0, 19.5, 18.0, 16.0, 14.5, 16.0, 18.0]),
+    (75.0, [60.0, 42.0, 24.0, 18.0, 16.0, 15.0, 14.0, 16.0, 18.0]),
+    (80.0, [56.0, 38.25, 23.0, 17.25, 15.81, 14.81, 14.0, 16.0, 18.0]),
+    (85.0, [52.0, 34.5, 22.0, 16.5, 15.625, 14.625, 14.0, 16.0, 18.0]),
+    (90.0, [48.0, 32.0, 21.0, 16.0, 15.5, 14.5, 14.0, 16.0, 18.0]),
+    (95.0, [45.0, 28.0, 19.5, 15.5, 15.0, 14.0, 13.5, 16.0, 18.0]),
+    (100.0, [42.0, 26.5, 18.5, 15.0, 14.5, 13.5, 13.0, 16.0, 18.0]),
+    (105.0, [39.0, 25.0, 18.0, 14.0, 14.0, 13.0, 12.0, 16.0, 18.0]),
+    (110.0, [36.0, 24.0, 18.0, 14.0, 13.0, 12.0, 11.0, 16.0, 18.0]),
+    (115.0, [34.5, 22.5, 17.25, 12.5, 11.875, 11.25, 10.625, 14.5, 16.5]),
+    (120.0, [33.0, 21.0, 16.5, 11.0, 10.75, 10.5, 10.25, 13.0, 15.0]),
+    (125.0, [32.0, 20.0, 16.0, 10.0, 10.0, 10.0, 10.0, 12.0, 14.0]),
+];
+
+fn apca_luminance(RgbColor { r, g, b }: RgbColor) -> f32 {
+
+    let channel = |value: u8| (f32::from(value) / 255.0).powf(MAIN_TRC);
+    let y = R_COEF * channel(r) + G_COEF * channel(g) + B_COEF * channel(b);
+
+    if y > BLK_THRS {
+        y
+    } else {
+        y + (BLK_THRS - y).powf(BLK_CLMP)
+    }
+}
+
+impl RgbColor {
+
+    /// APCA lightness contrast (Lc) of this color as text on the background,
+    /// about -108..106; positive for dark text on light background,
+    /// negative for light text on dark background
+    pub fn apca_contrast<T>(&self, background: T) -> f32 
+    where T: Into<RgbColor> {
+
+        let y_txt = apca_luminance(*self);
+        let y_bg = apca_luminance(background.into());
+
+        if (y_bg - y_txt).abs() < DELTA_Y_MIN {
+            return 0.0;
+        }
+
+        let lc = if y_bg > y_txt {
+            let sapc = (y_bg.powf(NORM_BG) - y_txt.powf(NORM_TXT)) * SCALE_BOW;
+            if sapc < LO_CLIP { 0.0 } else { sapc - LO_BOW_OFFSET }
+        } else {
+            let sapc = (y_bg.powf(REV_BG) - y_txt.powf(REV_TXT)) * SCALE_WOB;
+            if sapc > -LO_CLIP { 0.0 } else { sapc + LO_WOB_OFFSET }
+        };
+
+        lc * 100.0
+    }
+
+    /// Polarity of this color as text on the background
+    pub fn apca_polarity<T>(&self, background: T) -> ApcaPolarity 
+    where T: Into<RgbColor> {
+
+        if apca_luminance(background.into()) >= apca_luminance(*self) {
+            ApcaPolarity::DarkOnLight
+        } else {
+            ApcaPolarity::LightOnDark
+        }
+    }
+
+    /// Minimum font size (px) of this color as text on the background,
+    /// for the font weight (100..=900), using the APCA font lookup table;
+    /// Lc is rounded down to the table's 5 Lc steps, 
+    /// weights between the columns are rounded down to the lighter one;
+    /// returns `None` if the pair is not usable for text at that weight
+    pub fn apca_min_font_size<T>(&self, background: T, font_weight: u16) -> Option<f32> 
+    where T: Into<RgbColor> {
+
+        let lc = self.apca_contrast(background).abs();
+        let column = usize::from(font_weight.clamp(100, 900)) / 100 - 1;
+
+        let (_, sizes) = FONT_LOOKUP.iter()
+            .rev()
+            .find(|(row_lc, _)| lc >= *row_lc)?
+        ;
+        let size = sizes[column];
+
+        if size >= 777.0 { None } else { Some(size) }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use assert_float_eq::*;
+
+    fn lc(text: &str, background: &str) -> f32 {
+        let text = RgbColor::from_str(text).unwrap();
+        let background = RgbColor::from_str(background).unwrap();
+        text.apca_contrast(background)
+    }
+
+    #[test]
+    fn apca_reference_values() {
+        assert_float_absolute_eq!(lc("888888", "ffffff"), 63.056, 0.01);
+        assert_float_absolute_eq!(lc("ffffff", "888888"), -68.541, 0.01);
+        assert_float_absolute_eq!(lc("000000", "aaaaaa"), 58.146, 0.01);
+        assert_float_absolute_eq!(lc("aaaaaa", "000000"), -56.241, 0.01);
+        assert_float_absolute_eq!(lc("112233", "ddeeff"), 91.668, 0.01);
+        assert_float_absolute_eq!(lc("ddeeff", "112233"), -93.068, 0.01);
+        assert_float_absolute_eq!(lc("112233", "444444"), 8.323, 0.01);
+        assert_float_absolute_eq!(lc("444444", "112233"), -7.527, 0.01);
+    }
+
+    #[test]
+    fn apca_same_color() {
+        assert_f32_near!(lc("336699", "336699"), 0.0);
+    }
+
+    #[test]
+    fn apca_polarity() {
+        let black = RgbColor { r: 0, g: 0, b: 0 };
+        let white = RgbColor { r: 255, g: 255, b: 255 };
+        assert_eq!(black.apca_polarity(white), ApcaPolarity::DarkOnLight);
+        assert_eq!(white.apca_polarity(black), ApcaPolarity::LightOnDark);
+    }
+
+    #[test]
+    fn apca_font_size_lookup() {
+        let text = RgbColor::from_str("888888").unwrap();
+        let background = RgbColor::from_str("ffffff").unwrap();
+        assert_eq!(text.apca_min_font_size(background, 400), Some(24.0));
+        assert_eq!(text.apca_min_font_size(background, 700), Some(16.0));
+        assert_eq!(text.apca_min_font_size(background, 100), Some(72.0));
+    }
+
+    #[test]
+    fn apca_font_size_between_weights() {
+        let text = RgbColor::from_str("888888").unwrap();
+        let background = RgbColor::from_str("ffffff").unwrap();
+        assert_eq!(text.apca_min_font_size(background, 450), Some(24.0));
+        assert_eq!(text.apca_min_font_size(background, 499), Some(24.0));
+        assert_eq!(text.apca_min_font_size(background, 500), Some(21.0));
+    }
+
+    #[test]
+    fn apca_font_size_not_usable() {
+        let text = RgbColor::from_str("112233").unwrap();
+        let background = RgbColor::from_str("444444").unwrap();
+        assert_eq!(text.apca_min_font_size(background, 400), None);
+    }
+
+}
@@ -0,0 +1,185 @@
+/// Behaviour of the Saturation and Lightness channels
+/// when the spin goes beyond the 0..=100 range
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Boundary {
+    /// Stop at the limit, overflowing steps are the same (default)
+    #[default]
+    Clamp,
+    /// Bounce back from the limit, e.g. 110 becomes 90
+    Reflect,
+    /// Continue from the other end, e.g. 110 becomes 10
+    Wrap,
+    /// Shrink the whole ramp towards the base value, 
+    /// so the steps keep their proportions and fit into the range
+    Rescale,
+}
+
+impl Boundary {
+
+    /// Bring value into 0..=100 range, 
+    /// `Rescale` should be applied before, it clamps only
+    pub(crate) fn apply(self, value: f32) -> f32 {
+
+        if (0.0..=100.0).contains(&value) {
+            return value;
+        }
+
+        match self {
+            Boundary::Clamp | Boundary::Rescale => value.clamp(0.0, 100.0),
+            Boundary::Reflect => {
+                let value = value.rem_euclid(200.0);
+                if value > 100.0 { 200.0 - value } else { value }
+            },
+            Boundary::Wrap => value.rem_euclid(100.0),
+        }
+    }
+
+    /// Factor to shrink the distance of the values from the base,
+    /// so all of them fit into 0..=100 range
+    pub(crate) fn rescale_factor<I>(base: f32, values: I) -> f32
+    where I: IntoIterator<Item = f32> {
+
+        values.into_iter().fold(1.0, |factor: f32, value| {
+            let limit = if value > 100.0 {
+                100.0
+            } else if value < 0.0 {
+                0.0
+            } else {
+                return factor;
+            };
+            factor.min(((limit - base) / (value - base)).max(0.0))
+        })
+    }
+
+}
+
+/// Extremes of a ramp, whose steps depend linearly on the base value:
+/// `step = slope * base + intercept`;
+/// the envelopes are built once, then the extremes 
+/// for any base value are looked up in O(log count) time
+pub(crate) struct RampExtent {
+    upper: Envelope,
+    lower: Envelope,
+}
+
+impl RampExtent {
+
+    /// Steps are `(slope, intercept)` pairs
+    pub(crate) fn new(steps: &[(f32, f32)]) -> Self {
+        let negated: Vec<(f32, f32)> = steps.iter()
+            .map(|&(slope, intercept)| (-slope, -intercept))
+            .collect()
+        ;
+        Self {
+            upper: Envelope::new(steps),
+            lower: Envelope::new(&negated),
+        }
+    }
+
+    /// Factor of [`Boundary::Rescale`] for the base value
+    pub(crate) fn rescale_factor(&self, base: f32) -> f32 {
+        let max = self.upper.max_at(base);
+        let min = -self.lower.max_at(base);
+        Boundary::rescale_factor(base, [min, max])
+    }
+
+}
+
+/// Upper envelope of lines, ordered by slope, 
+/// with the positions where the next line takes over
+struct Envelope {
+    lines: Vec<(f32, f32)>,
+    breaks: Vec<f32>,
+}
+
+impl Envelope {
+
+    fn new(lines: &[(f32, f32)]) -> Self {
+
+        let mut sorted = lines.to_vec();
+        sorted.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+
+        let mut hull: Vec<(f32, f32)> = Vec::with_capacity(sorted.len());
+        for line in sorted {
+            if let Some(last) = hull.last() {
+                if last.0 == line.0 {
+                    hull.pop();
+                }
+            }
+            while hull.len() >= 2 {
+                let first = hull[hull.len() - 2];
+                let last = hull[hull.len() - 1];
+                if Self::intersection(first, line) <= Self::intersection(first, last) {
+                    hull.pop();
+                } else {
+                    break;
+                }
+            }
+            hull.push(line);
+        }
+
+        let breaks = hull.windows(2)
+            .map(|pair| Self::intersection(pair[0], pair[1]))
+            .collect()
+        ;
+
+        Self { lines: hull, breaks }
+    }
+
+    /// Position where the lines meet, slopes must differ
+    fn intersection((slope1, intercept1): (f32, f32), (slope2, intercept2): (f32, f32)) -> f32 {
+        (intercept1 - intercept2) / (slope2 - slope1)
+    }
+
+    fn max_at(&self, x: f32) -> f32 {
+
+        if self.lines.is_empty() {
+            return x;
+        }
+
+        let index = self.breaks.partition_point(|&position| position < x);
+        let (slope, intercept) = self.lines[index];
+
+        slope * x + intercept
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    #[test]
+    fn boundary_apply() {
+        assert_f32_near!(Boundary::Clamp.apply(110.0), 100.0);
+        assert_f32_near!(Boundary::Reflect.apply(110.0), 90.0);
+        assert_f32_near!(Boundary::Reflect.apply(-30.0), 30.0);
+        assert_f32_near!(Boundary::Wrap.apply(110.0), 10.0);
+        assert_f32_near!(Boundary::Wrap.apply(-30.0), 70.0);
+        assert_f32_near!(Boundary::Wrap.apply(100.0), 100.0);
+    }
+
+    #[test]
+    fn boundary_rescale_factor() {
+        assert_f32_near!(Boundary::rescale_factor(80.0, [80.0, 100.0, 120.0]), 0.5);
+        assert_f32_near!(Boundary::rescale_factor(20.0, [20.0, -20.0, 60.0]), 0.5);
+        assert_f32_near!(Boundary::rescale_factor(50.0, [50.0, 70.0]), 1.0);
+    }
+
+    #[test]
+    fn boundary_ramp_extent_same_as_scan() {
+
+        let steps = [(1.0, 0.0), (0.5, 60.0), (0.0, 90.0), (1.0, 25.0), (0.25, -10.0), (0.75, 10.0)];
+        let extent = RampExtent::new(&steps);
+
+        for base in [0.0, 10.0, 33.3, 50.0, 80.0, 100.0] {
+            let values = steps.iter().map(|&(slope, intercept)| slope * base + intercept);
+            let expected = Boundary::rescale_factor(base, values);
+            assert_f32_near!(extent.rescale_factor(base), expected, 99999);
+        }
+    }
+
+}
@@ -0,0 +1,168 @@
+//! Serde support, behind the `serde` feature:
+//! - `RgbColor` and `RgbaColor` are serialized as hex string,
+//! - `HslColor` and `HslaColor` are serialized as struct,
+//! - all of them can be deserialized from both forms,
+//!   alpha is optional in the struct form of `HslaColor`,
+//!   the string form accepts any format of the CSS parser.
+use serde::{ Deserialize, Deserializer, Serialize, Serializer };
+use serde::de::Error;
+use crate::hsl_color::HslColor;
+use crate::hsla_color::HslaColor;
+use crate::rgb_color::RgbColor;
+use crate::rgba_color::RgbaColor;
+use crate::css_format::CssFormat;
+use crate::css_parser::parse_css_color;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RgbRepr {
+    Text(String),
+    Struct { r: u8, g: u8, b: u8 },
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RgbaRepr {
+    Text(String),
+    Struct { r: u8, g: u8, b: u8, a: u8 },
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HslRepr {
+    Text(String),
+    Struct { h: f32, s: f32, l: f32 },
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HslaRepr {
+    Text(String),
+    Struct { h: f32, s: f32, l: f32, #[serde(default = "opaque")] a: f32 },
+}
+
+fn opaque() -> f32 {
+    100.0
+}
+
+fn parse_text<E: Error>(text: &str) -> Result<[f32; 4], E> {
+    parse_css_color(text).map_err(|err| E::custom(format!("invalid color {:?}: {}", text, err)))
+}
+
+impl Serialize for RgbColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_css(CssFormat::Hex))
+    }
+}
+
+impl<'de> Deserialize<'de> for RgbColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match RgbRepr::deserialize(deserializer)? {
+            RgbRepr::Text(text) => {
+                let [r, g, b, _] = parse_text(&text)?;
+                Ok(RgbColor::from([r, g, b]))
+            },
+            RgbRepr::Struct { r, g, b } => Ok(RgbColor { r, g, b }),
+        }
+    }
+}
+
+impl Serialize for RgbaColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let RgbaColor { r, g, b, a } = *self;
+        serializer.serialize_str(&format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a))
+    }
+}
+
+impl<'de> Deserialize<'de> for RgbaColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match RgbaRepr::deserialize(deserializer)? {
+            RgbaRepr::Text(text) => Ok(RgbaColor::from(parse_text(&text)?)),
+            RgbaRepr::Struct { r, g, b, a } => Ok(RgbaColor { r, g, b, a }),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for HslColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match HslRepr::deserialize(deserializer)? {
+            HslRepr::Text(text) => {
+                let [r, g, b, _] = parse_text(&text)?;
+                Ok(HslColor::convert_rgb_f32_to_hsl([r, g, b]))
+            },
+            HslRepr::Struct { h, s, l } => Ok(HslColor { h, s, l }),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for HslaColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match HslaRepr::deserialize(deserializer)? {
+            HslaRepr::Text(text) => {
+                let [r, g, b, a] = parse_text(&text)?;
+                Ok(HslaColor::from_hsl(HslColor::convert_rgb_f32_to_hsl([r, g, b]), a * 100.0))
+            },
+            HslaRepr::Struct { h, s, l, a } => Ok(HslaColor { h, s, l, a }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    #[test]
+    fn serde_rgb_hex_and_struct() {
+        let rgb = RgbColor { r: 0x1A, g: 0xF9, b: 0xCC };
+        assert_eq!(serde_json::to_string(&rgb).unwrap(), "\"#1af9cc\"");
+        let result: RgbColor = serde_json::from_str("\"#1af9cc\"").unwrap();
+        assert_eq!(result, rgb);
+        let result: RgbColor = serde_json::from_str(r#"{"r":26,"g":249,"b":204}"#).unwrap();
+        assert_eq!(result, rgb);
+        let result: RgbColor = serde_json::from_str("\"rgb(26 249 204)\"").unwrap();
+        assert_eq!(result, rgb);
+    }
+
+    #[test]
+    fn serde_rgba_roundtrip() {
+        let rgba = RgbaColor { r: 0x1A, g: 0xF9, b: 0xCC, a: 0x80 };
+        let text = serde_json::to_string(&rgba).unwrap();
+        assert_eq!(text, "\"#1af9cc80\"");
+        let result: RgbaColor = serde_json::from_str(&text).unwrap();
+        assert_eq!(result, rgba);
+    }
+
+    #[test]
+    fn serde_hsl_struct_and_string() {
+        let hsl = HslColor::new(210, 80, 40);
+        let text = serde_json::to_string(&hsl).unwrap();
+        assert_eq!(text, r#"{"h":210.0,"s":80.0,"l":40.0}"#);
+        let result: HslColor = serde_json::from_str(&text).unwrap();
+        assert_eq!(result, hsl);
+
+        let result: HslColor = serde_json::from_str("\"hsl(210 80% 40%)\"").unwrap();
+        assert_float_absolute_eq!(result.h, 210.0, 0.01);
+        assert_float_absolute_eq!(result.s, 80.0, 0.01);
+        assert_float_absolute_eq!(result.l, 40.0, 0.01);
+    }
+
+    #[test]
+    fn serde_hsla_from_string() {
+        let result: HslaColor = serde_json::from_str("\"hsl(0 100% 50% / 25%)\"").unwrap();
+        assert_float_absolute_eq!(result.a, 25.0, 0.01);
+    }
+
+    #[test]
+    fn serde_hsla_struct_default_alpha() {
+        let result: HslaColor = serde_json::from_str(r#"{"h":120,"s":50,"l":40}"#).unwrap();
+        assert_eq!(result, HslaColor::new(120, 50, 40, 100));
+    }
+
+    #[test]
+    fn serde_invalid_color() {
+        let result: Result<RgbColor, _> = serde_json::from_str("\"bluish\"");
+        assert!(result.is_err());
+    }
+
+}
@@ -0,0 +1,103 @@
+use std::convert::From;
+use crate::hsl_color::HslColor;
+use crate::rgb_color::RgbColor;
+use crate::hsv_color::HsvColor;
+use crate::hwb_color::HwbColor;
+
+impl From<HslColor> for HsvColor {
+    fn from(HslColor { h, s, l }: HslColor) -> Self {
+
+        let s = s / 100.0;
+        let l = l / 100.0;
+
+        let v = l + s * l.min(1.0 - l);
+        let s = if v <= 0.0 { 0.0 } else { 2.0 * (1.0 - l / v) };
+
+        HsvColor { h, s: s * 100.0, v: v * 100.0 }
+    }
+}
+
+impl From<RgbColor> for HsvColor {
+    fn from(val: RgbColor) -> Self {
+        HsvColor::from(HslColor::from(val))
+    }
+}
+
+impl From<HsvColor> for HwbColor {
+    fn from(HsvColor { h, s, v }: HsvColor) -> Self {
+
+        let s = s / 100.0;
+        let v = v / 100.0;
+
+        let w = (1.0 - s) * v;
+        let b = 1.0 - v;
+
+        HwbColor { h, w: w * 100.0, b: b * 100.0 }
+    }
+}
+
+impl From<HslColor> for HwbColor {
+    fn from(val: HslColor) -> Self {
+        HwbColor::from(HsvColor::from(val))
+    }
+}
+
+impl From<RgbColor> for HwbColor {
+    fn from(val: RgbColor) -> Self {
+        HwbColor::from(HslColor::from(val))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    #[test]
+    fn hsl_to_hsv_red() {
+        let hsv = HsvColor::from(HslColor::new(0, 100, 50));
+        assert_f32_near!(hsv.h, 0.0);
+        assert_f32_near!(hsv.s, 100.0);
+        assert_f32_near!(hsv.v, 100.0);
+    }
+
+    #[test]
+    fn hsl_to_hsv_white() {
+        let hsv = HsvColor::from(HslColor::new(0, 0, 100));
+        assert_f32_near!(hsv.s, 0.0);
+        assert_f32_near!(hsv.v, 100.0);
+    }
+
+    #[test]
+    fn hsl_to_hsv_roundtrip() {
+        let original = HslColor::new(33, 71, 28);
+        let hsl = HslColor::from(HsvColor::from(original));
+        assert_float_absolute_eq!(hsl.h, original.h, 0.001);
+        assert_float_absolute_eq!(hsl.s, original.s, 0.001);
+        assert_float_absolute_eq!(hsl.l, original.l, 0.001);
+    }
+
+    #[test]
+    fn rgb_to_hsv_roundtrip() {
+        let original = RgbColor { r: 30, g: 144, b: 200 };
+        let rgb = RgbColor::from(HsvColor::from(original));
+        assert_eq!(rgb, original);
+    }
+
+    #[test]
+    fn hsl_to_hwb_roundtrip() {
+        let original = HslColor::new(300, 40, 65);
+        let hsl = HslColor::from(HwbColor::from(original));
+        assert_float_absolute_eq!(hsl.h, original.h, 0.001);
+        assert_float_absolute_eq!(hsl.s, original.s, 0.001);
+        assert_float_absolute_eq!(hsl.l, original.l, 0.001);
+    }
+
+    #[test]
+    fn rgb_to_hwb_roundtrip() {
+        let original = RgbColor { r: 250, g: 128, b: 114 };
+        let rgb = RgbColor::from(HwbColor::from(original));
+        assert_eq!(rgb, original);
+    }
+
+}
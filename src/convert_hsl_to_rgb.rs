@@ -11,14 +11,18 @@ impl From<HslColor> for RgbColor {
 impl HslColor {
 
     fn convert_hsl_to_rgb(&self) -> RgbColor {
-        
+        RgbColor::from(self.convert_hsl_to_rgb_f32())
+    } 
+
+    /// Unquantized conversion, channels are in 0..=1 range
+    pub(crate) fn convert_hsl_to_rgb_f32(&self) -> [f32; 3] {
+
         let h = self.h / 360.0;
         let s = self.s / 100.0;
         let l = self.l / 100.0;
 
         if s < 0.001 {
-            let gray = (l * 255.0) as u8;
-            return RgbColor { r: gray, g: gray, b: gray };
+            return [l, l, l];
         }
 
         let q = if l < 0.5 { 
@@ -32,16 +36,8 @@ impl HslColor {
         let g = Self::hue_to_rgb_component(p, q, h);
         let b = Self::hue_to_rgb_component(p, q, h - (1.0/3.0));
 
-        let r = (r * 12000.0).round() / 12000.0;
-        let g = (g * 12000.0).round() / 12000.0;
-        let b = (b * 12000.0).round() / 12000.0;
-
-        let r = (r * 255.0) as u8;
-        let g = (g * 255.0) as u8;
-        let b = (b * 255.0) as u8;
-        
-        RgbColor { r, g, b }
-    } 
+        [r, g, b]
+    }
 
     fn hue_to_rgb_component(p: f32, q: f32, mut t: f32) -> f32 {
         
@@ -0,0 +1,40 @@
+use std::convert::From;
+use crate::hsl_color::HslColor;
+use crate::hsla_color::HslaColor;
+use crate::rgba_color::RgbaColor;
+
+impl From<HslaColor> for RgbaColor {
+    fn from(val: HslaColor) -> Self {
+        let [r, g, b] = HslColor::from(val).convert_hsl_to_rgb_f32();
+        RgbaColor::from([r, g, b, val.a / 100.0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rgb_color::RgbColor;
+
+    #[test]
+    fn hsla_to_rgba_red() {
+        let hsla = HslaColor::new(0, 100, 50, 100);
+        let rgba: RgbaColor = hsla.into();
+        assert_eq!(rgba, RgbaColor { r: 255, g: 0, b: 0, a: 255 });
+    }
+
+    #[test]
+    fn hsla_to_rgba_same_as_rgb() {
+        let hsl = HslColor::new(0, 0, 3);
+        let rgb = RgbColor::from(hsl);
+        let rgba = RgbaColor::from(HslaColor::from(hsl));
+        assert_eq!(rgba, RgbaColor::from(rgb));
+    }
+
+    #[test]
+    fn hsla_to_rgba_translucent_blue() {
+        let hsla = HslaColor::new(240, 100, 50, 50);
+        let rgba: RgbaColor = hsla.into();
+        assert_eq!(rgba, RgbaColor { r: 0, g: 0, b: 255, a: 128 });
+    }
+
+}
@@ -0,0 +1,110 @@
+use std::convert::From;
+use crate::hsl_color::HslColor;
+use crate::rgb_color::RgbColor;
+use crate::hsv_color::HsvColor;
+use crate::hwb_color::HwbColor;
+
+impl From<HsvColor> for HslColor {
+    fn from(HsvColor { h, s, v }: HsvColor) -> Self {
+
+        let s = s / 100.0;
+        let v = v / 100.0;
+
+        let l = v * (1.0 - s / 2.0);
+        let min = l.min(1.0 - l);
+        let s = if min <= 0.0 { 0.0 } else { (v - l) / min };
+
+        HslColor { h, s: s * 100.0, l: l * 100.0 }
+    }
+}
+
+impl From<HsvColor> for RgbColor {
+    fn from(val: HsvColor) -> Self {
+        RgbColor::from(HslColor::from(val))
+    }
+}
+
+impl From<HwbColor> for HsvColor {
+    fn from(HwbColor { h, w, b }: HwbColor) -> Self {
+
+        let w = w / 100.0;
+        let b = b / 100.0;
+
+        if w + b >= 1.0 {
+            let gray = w / (w + b);
+            return HsvColor { h, s: 0.0, v: gray * 100.0 };
+        }
+
+        let v = 1.0 - b;
+        let s = 1.0 - w / v;
+
+        HsvColor { h, s: s * 100.0, v: v * 100.0 }
+    }
+}
+
+impl From<HwbColor> for HslColor {
+    fn from(val: HwbColor) -> Self {
+        HslColor::from(HsvColor::from(val))
+    }
+}
+
+impl From<HwbColor> for RgbColor {
+    fn from(val: HwbColor) -> Self {
+        RgbColor::from(HslColor::from(val))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    #[test]
+    fn hsv_to_hsl_red() {
+        let hsl = HslColor::from(HsvColor::new(0, 100, 100));
+        assert_f32_near!(hsl.h, 0.0);
+        assert_f32_near!(hsl.s, 100.0);
+        assert_f32_near!(hsl.l, 50.0);
+    }
+
+    #[test]
+    fn hsv_to_hsl_pastel() {
+        let hsl = HslColor::from(HsvColor::new(210, 50, 80));
+        assert_f32_near!(hsl.h, 210.0);
+        assert_float_absolute_eq!(hsl.s, 50.0, 0.001);
+        assert_float_absolute_eq!(hsl.l, 60.0, 0.001);
+    }
+
+    #[test]
+    fn hsv_to_hsl_black() {
+        let hsl = HslColor::from(HsvColor::new(0, 100, 0));
+        assert_f32_near!(hsl.s, 0.0);
+        assert_f32_near!(hsl.l, 0.0);
+    }
+
+    #[test]
+    fn hsv_to_rgb_blue() {
+        let rgb = RgbColor::from(HsvColor::new(240, 100, 100));
+        assert_eq!(rgb, RgbColor { r: 0, g: 0, b: 255 });
+    }
+
+    #[test]
+    fn hwb_to_hsv_gray() {
+        let hsv = HsvColor::from(HwbColor::new(0, 60, 60));
+        assert_f32_near!(hsv.s, 0.0);
+        assert_float_absolute_eq!(hsv.v, 50.0, 0.001);
+    }
+
+    #[test]
+    fn hwb_to_rgb_green() {
+        let rgb = RgbColor::from(HwbColor::new(120, 0, 0));
+        assert_eq!(rgb, RgbColor { r: 0, g: 255, b: 0 });
+    }
+
+    #[test]
+    fn hwb_to_rgb_tint() {
+        let rgb = RgbColor::from(HwbColor::new(0, 20, 20));
+        assert_eq!(rgb, RgbColor { r: 204, g: 51, b: 51 });
+    }
+
+}
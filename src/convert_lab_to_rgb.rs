@@ -0,0 +1,157 @@
+use std::convert::From;
+use crate::hsl_color::HslColor;
+use crate::rgb_color::RgbColor;
+use crate::lab_color::LabColor;
+use crate::lch_color::LchColor;
+use crate::linear_rgb::linear_to_srgb;
+use crate::convert_rgb_to_lab::{ WHITE_D65, LAB_EPSILON, LAB_KAPPA };
+
+impl From<LabColor> for RgbColor {
+    fn from(val: LabColor) -> Self {
+        RgbColor::from(val.convert_lab_to_rgb_f32())
+    }
+}
+
+impl From<LabColor> for HslColor {
+    fn from(val: LabColor) -> Self {
+        HslColor::convert_rgb_f32_to_hsl(val.convert_lab_to_rgb_f32())
+    }
+}
+
+impl From<LchColor> for RgbColor {
+    fn from(val: LchColor) -> Self {
+        RgbColor::from(LabColor::from(val))
+    }
+}
+
+impl From<LchColor> for HslColor {
+    fn from(val: LchColor) -> Self {
+        HslColor::from(LabColor::from(val))
+    }
+}
+
+impl From<LchColor> for LabColor {
+    fn from(LchColor { l, c, h }: LchColor) -> Self {
+        let h = h.to_radians();
+        LabColor { l, a: c * h.cos(), b: c * h.sin() }
+    }
+}
+
+/// CIE XYZ (D65) to linear sRGB
+pub(crate) fn convert_xyz_to_linear_rgb([x, y, z]: [f32; 3]) -> [f32; 3] {
+    [
+        3.2404542 * x - 1.5371385 * y - 0.4985314 * z,
+        -0.969266 * x + 1.8760108 * y + 0.0415560 * z,
+        0.0556434 * x - 0.2040259 * y + 1.0572252 * z,
+    ]
+}
+
+impl LabColor {
+
+    /// Unquantized conversion, result channels are clamped into 0..=1 range
+    pub(crate) fn convert_lab_to_rgb_f32(&self) -> [f32; 3] {
+
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = fy + self.a / 500.0;
+        let fz = fy - self.b / 200.0;
+
+        let x = Self::lab_f_inv(fx);
+        let y = if self.l > LAB_KAPPA * LAB_EPSILON { 
+            fy * fy * fy 
+        } else { 
+            self.l / LAB_KAPPA 
+        };
+        let z = Self::lab_f_inv(fz);
+
+        let xyz = [x * WHITE_D65[0], y * WHITE_D65[1], z * WHITE_D65[2]];
+        let linear = convert_xyz_to_linear_rgb(xyz);
+
+        linear.map(|value| linear_to_srgb(value.clamp(0.0, 1.0)))
+    }
+
+    fn lab_f_inv(f: f32) -> f32 {
+        let f3 = f * f * f;
+        if f3 > LAB_EPSILON {
+            f3
+        } else {
+            (116.0 * f - 16.0) / LAB_KAPPA
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lab_to_rgb_white() {
+        let lab = LabColor::new(100.0, 0.0, 0.0);
+        let rgb: RgbColor = lab.into();
+        assert_eq!(rgb, RgbColor { r: 255, g: 255, b: 255 });
+    }
+
+    #[test]
+    fn lab_to_rgb_black() {
+        let lab = LabColor::new(0.0, 0.0, 0.0);
+        let rgb: RgbColor = lab.into();
+        assert_eq!(rgb, RgbColor { r: 0, g: 0, b: 0 });
+    }
+
+    #[test]
+    fn lab_to_rgb_red() {
+        let lab = LabColor::new(53.24, 80.09, 67.20);
+        let rgb: RgbColor = lab.into();
+        assert_eq!(rgb, RgbColor { r: 255, g: 0, b: 0 });
+    }
+
+    #[test]
+    fn lab_to_rgb_roundtrip() {
+        for original in [
+            RgbColor { r: 30, g: 144, b: 200 },
+            RgbColor { r: 1, g: 2, b: 3 },
+            RgbColor { r: 250, g: 128, b: 114 },
+            RgbColor { r: 127, g: 127, b: 127 },
+        ] {
+            let lab = LabColor::from(original);
+            let rgb: RgbColor = lab.into();
+            assert_eq!(rgb, original);
+        }
+    }
+
+    #[test]
+    fn lch_to_rgb_roundtrip() {
+        let original = RgbColor { r: 80, g: 0, b: 120 };
+        let lch = LchColor::from(original);
+        let rgb: RgbColor = lch.into();
+        assert_eq!(rgb, original);
+    }
+
+    #[test]
+    fn lab_to_hsl_roundtrip() {
+        let original = HslColor::new(200, 60, 40);
+        let lab = LabColor::from(original);
+        let hsl: HslColor = lab.into();
+        assert!((hsl.h - original.h).abs() < 0.1);
+        assert!((hsl.s - original.s).abs() < 0.1);
+        assert!((hsl.l - original.l).abs() < 0.1);
+    }
+
+    #[test]
+    fn lch_to_hsl_roundtrip() {
+        let original = HslColor::new(30, 80, 70);
+        let lch = LchColor::from(original);
+        let hsl: HslColor = lch.into();
+        assert!((hsl.h - original.h).abs() < 0.1);
+        assert!((hsl.s - original.s).abs() < 0.1);
+        assert!((hsl.l - original.l).abs() < 0.1);
+    }
+
+    #[test]
+    fn lch_to_lab_red() {
+        let lch = LchColor::new(53.24, 104.55, 40.0);
+        let lab = LabColor::from(lch);
+        assert!((lab.a - 80.09).abs() < 0.01);
+        assert!((lab.b - 67.20).abs() < 0.01);
+    }
+
+}
@@ -0,0 +1,128 @@
+use std::convert::From;
+use crate::hsl_color::HslColor;
+use crate::rgb_color::RgbColor;
+use crate::oklch_color::OklchColor;
+use crate::linear_rgb::linear_to_srgb;
+
+impl From<OklchColor> for RgbColor {
+    fn from(val: OklchColor) -> Self {
+        RgbColor::from(val.convert_oklch_to_rgb_f32())
+    }
+}
+
+impl From<OklchColor> for HslColor {
+    fn from(val: OklchColor) -> Self {
+        HslColor::convert_rgb_f32_to_hsl(val.convert_oklch_to_rgb_f32())
+    }
+}
+
+/// OKLab (`[L, a, b]`) to linear sRGB, by Björn Ottosson
+#[allow(clippy::excessive_precision)]
+pub(crate) fn convert_oklab_to_linear_rgb([l, a, b]: [f32; 3]) -> [f32; 3] {
+
+    let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+    let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+    let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+
+    let l = l_ * l_ * l_;
+    let m = m_ * m_ * m_;
+    let s = s_ * s_ * s_;
+
+    [
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    ]
+}
+
+impl OklchColor {
+
+    /// Unquantized conversion, result channels are in 0..=1 range;
+    /// out-of-gamut colors are mapped into sRGB by reducing chroma
+    pub(crate) fn convert_oklch_to_rgb_f32(&self) -> [f32; 3] {
+
+        let l = self.l.clamp(0.0, 1.0);
+        let c = self.c.max(0.0);
+
+        let mut linear = Self::convert_lch_to_linear_rgb(l, c, self.h);
+
+        if !Self::is_in_gamut(&linear) {
+            let mut c_lo = 0.0;
+            let mut c_hi = c;
+            for _ in 0..16 {
+                let c_mid = (c_lo + c_hi) / 2.0;
+                if Self::is_in_gamut(&Self::convert_lch_to_linear_rgb(l, c_mid, self.h)) {
+                    c_lo = c_mid;
+                } else {
+                    c_hi = c_mid;
+                }
+            }
+            linear = Self::convert_lch_to_linear_rgb(l, c_lo, self.h);
+        }
+
+        linear.map(|value| linear_to_srgb(value.clamp(0.0, 1.0)))
+    }
+
+    fn convert_lch_to_linear_rgb(l: f32, c: f32, h: f32) -> [f32; 3] {
+        let h = h.to_radians();
+        convert_oklab_to_linear_rgb([l, c * h.cos(), c * h.sin()])
+    }
+
+    fn is_in_gamut(linear: &[f32; 3]) -> bool {
+        const EPSILON: f32 = 1e-4;
+        linear.iter().all(|value| (-EPSILON..=1.0 + EPSILON).contains(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oklch_to_rgb_white() {
+        let oklch = OklchColor::new(1.0, 0.0, 0.0);
+        let rgb: RgbColor = oklch.into();
+        assert_eq!(rgb, RgbColor { r: 255, g: 255, b: 255 });
+    }
+
+    #[test]
+    fn oklch_to_rgb_black() {
+        let oklch = OklchColor::new(0.0, 0.0, 0.0);
+        let rgb: RgbColor = oklch.into();
+        assert_eq!(rgb, RgbColor { r: 0, g: 0, b: 0 });
+    }
+
+    #[test]
+    fn oklch_to_rgb_red() {
+        let oklch = OklchColor::new(0.62796, 0.25768, 29.234);
+        let rgb: RgbColor = oklch.into();
+        assert_eq!(rgb, RgbColor { r: 255, g: 0, b: 0 });
+    }
+
+    #[test]
+    fn oklch_to_rgb_roundtrip() {
+        let original = RgbColor { r: 30, g: 144, b: 200 };
+        let oklch = OklchColor::from(original);
+        let rgb: RgbColor = oklch.into();
+        assert_eq!(rgb, original);
+    }
+
+    #[test]
+    fn oklch_to_rgb_out_of_gamut() {
+        let oklch = OklchColor::new(0.9, 0.4, 145.0);
+        let rgb: RgbColor = oklch.into();
+        assert!(rgb.g > rgb.r);
+        assert!(rgb.g > rgb.b);
+    }
+
+    #[test]
+    fn oklch_to_hsl_roundtrip() {
+        let original = HslColor::new(200, 60, 40);
+        let oklch = OklchColor::from(original);
+        let hsl: HslColor = oklch.into();
+        assert!((hsl.h - original.h).abs() < 0.1);
+        assert!((hsl.s - original.s).abs() < 0.1);
+        assert!((hsl.l - original.l).abs() < 0.1);
+    }
+
+}
@@ -3,32 +3,39 @@ use crate::hsl_color::HslColor;
 use crate::rgb_color::RgbColor;
 
 impl From<RgbColor> for HslColor {    
-    fn from(RgbColor { r, g, b }: RgbColor) -> Self {
+    fn from(rgb: RgbColor) -> Self {
+        HslColor::convert_rgb_f32_to_hsl(rgb.to_rgb_f32())
+    }
+}
+
+impl HslColor {
+
+    /// Unquantized conversion, channels should be in 0..=1 range
+    pub(crate) fn convert_rgb_f32_to_hsl([r, g, b]: [f32; 3]) -> Self {
 
-        let min = r.min(g).min(b);
-        let max = r.max(g).max(b);
-        let fmin = f32::from(min) / 255.0;
-        let fmax = f32::from(max) / 255.0;
+        let fmin = r.min(g).min(b);
+        let fmax = r.max(g).max(b);
         let d = fmax - fmin;
+        let is_gray = d < 1e-6;
         let l = (fmin + fmax) / 2.0;
-        let s = if min == max {
+        let s = if is_gray {
             0.0
         } else {
             f32::clamp(d / (1.0 - f32::abs(2.0 * l - 1.0)), 0.0, 1.0)
         };
-        let h = if min == max {
+        let h = if is_gray {
             0.0
+        } else if fmax == r {
+            (g - b) / d
+        } else if fmax == g {
+            (b - r) / d + 2.0
         } else {
-            let (r, g, b) = (f32::from(r), f32::from(g), f32::from(b));
-            let numer = r - 0.5 * (g + b);
-            let denom = f32::sqrt(r * r + g * g + b * b - r * g - r * b - g * b);
-            let angle = f32::acos(numer / denom);
-
-            if g < b { core::f32::consts::TAU - angle } else { angle }
+            (r - g) / d + 4.0
         };
+        let h = h * 60.0;
         
         HslColor {
-            h: h.to_degrees(),
+            h: if h < 0.0 { h + 360.0 } else { h },
             s: s * 100.0,
             l: l * 100.0,
         }                    
@@ -92,4 +99,12 @@ mod tests {
         assert_f32_near!(hsl.l, 6.1, 99999);
     }
 
+    #[test]
+    fn rgb_to_hsl_roundtrip() {
+        let original = RgbColor { r: 30, g: 144, b: 200 };
+        let hsl = HslColor::from(original);
+        let rgb: RgbColor = hsl.into();
+        assert_eq!(rgb, original);
+    }
+
 }
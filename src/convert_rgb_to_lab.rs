@@ -0,0 +1,162 @@
+use std::convert::From;
+use crate::hsl_color::HslColor;
+use crate::rgb_color::RgbColor;
+use crate::lab_color::LabColor;
+use crate::lch_color::LchColor;
+use crate::linear_rgb::srgb_to_linear;
+
+/// D65 reference white in XYZ
+pub(crate) const WHITE_D65: [f32; 3] = [0.95047, 1.0, 1.08883];
+
+/// CIE constants, exact rational forms
+pub(crate) const LAB_EPSILON: f32 = 216.0 / 24389.0;
+pub(crate) const LAB_KAPPA: f32 = 24389.0 / 27.0;
+
+impl From<RgbColor> for LabColor {    
+    fn from(RgbColor { r, g, b }: RgbColor) -> Self {
+        let r = f32::from(r) / 255.0;
+        let g = f32::from(g) / 255.0;
+        let b = f32::from(b) / 255.0;
+        LabColor::convert_rgb_f32_to_lab([r, g, b])
+    }
+}
+
+impl From<HslColor> for LabColor {    
+    fn from(hsl: HslColor) -> Self {
+        LabColor::convert_rgb_f32_to_lab(hsl.convert_hsl_to_rgb_f32())
+    }
+}
+
+impl From<RgbColor> for LchColor {    
+    fn from(rgb: RgbColor) -> Self {
+        LchColor::from(LabColor::from(rgb))
+    }
+}
+
+impl From<HslColor> for LchColor {    
+    fn from(hsl: HslColor) -> Self {
+        LchColor::from(LabColor::from(hsl))
+    }
+}
+
+impl From<LabColor> for LchColor {    
+    fn from(LabColor { l, a, b }: LabColor) -> Self {
+
+        let c = f32::sqrt(a * a + b * b);
+        let h = if c < 1e-4 {
+            0.0
+        } else {
+            let h = f32::atan2(b, a).to_degrees();
+            if h < 0.0 { h + 360.0 } else { h }
+        };
+
+        LchColor { l, c, h }
+    }
+}
+
+/// Linear sRGB to CIE XYZ (D65)
+pub(crate) fn convert_linear_rgb_to_xyz([r, g, b]: [f32; 3]) -> [f32; 3] {
+    [
+        0.4124564 * r + 0.3575761 * g + 0.1804375 * b,
+        0.2126729 * r + 0.7151522 * g + 0.0721750 * b,
+        0.0193339 * r + 0.119192 * g + 0.9503041 * b,
+    ]
+}
+
+impl LabColor {
+
+    /// Unquantized conversion, channels should be in 0..=1 range
+    pub(crate) fn convert_rgb_f32_to_lab([r, g, b]: [f32; 3]) -> Self {
+
+        let linear = [srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b)];
+        let xyz = convert_linear_rgb_to_xyz(linear);
+
+        let fx = Self::lab_f(xyz[0] / WHITE_D65[0]);
+        let fy = Self::lab_f(xyz[1] / WHITE_D65[1]);
+        let fz = Self::lab_f(xyz[2] / WHITE_D65[2]);
+
+        LabColor {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    fn lab_f(t: f32) -> f32 {
+        if t > LAB_EPSILON {
+            t.cbrt()
+        } else {
+            (LAB_KAPPA * t + 16.0) / 116.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    #[test]
+    fn rgb_to_lab_white() {
+        let rgb = RgbColor { r: 255, g: 255, b: 255 };
+        let lab = LabColor::from(rgb);
+        assert_float_absolute_eq!(lab.l, 100.0, 0.01);
+        assert_float_absolute_eq!(lab.a, 0.0, 0.01);
+        assert_float_absolute_eq!(lab.b, 0.0, 0.01);
+    }
+
+    #[test]
+    fn rgb_to_lab_black() {
+        let rgb = RgbColor { r: 0, g: 0, b: 0 };
+        let lab = LabColor::from(rgb);
+        assert_float_absolute_eq!(lab.l, 0.0, 0.01);
+        assert_float_absolute_eq!(lab.a, 0.0, 0.01);
+        assert_float_absolute_eq!(lab.b, 0.0, 0.01);
+    }
+
+    #[test]
+    fn rgb_to_lab_red() {
+        let rgb = RgbColor { r: 255, g: 0, b: 0 };
+        let lab = LabColor::from(rgb);
+        assert_float_absolute_eq!(lab.l, 53.24, 0.01);
+        assert_float_absolute_eq!(lab.a, 80.09, 0.01);
+        assert_float_absolute_eq!(lab.b, 67.20, 0.01);
+    }
+
+    #[test]
+    fn rgb_to_lab_blue() {
+        let rgb = RgbColor { r: 0, g: 0, b: 255 };
+        let lab = LabColor::from(rgb);
+        assert_float_absolute_eq!(lab.l, 32.30, 0.01);
+        assert_float_absolute_eq!(lab.a, 79.19, 0.01);
+        assert_float_absolute_eq!(lab.b, -107.86, 0.01);
+    }
+
+    #[test]
+    fn hsl_to_lab_gray() {
+        let hsl = HslColor::new(0, 0, 50);
+        let lab = LabColor::from(hsl);
+        assert_float_absolute_eq!(lab.l, 53.39, 0.01);
+        assert_float_absolute_eq!(lab.a, 0.0, 0.01);
+        assert_float_absolute_eq!(lab.b, 0.0, 0.01);
+    }
+
+    #[test]
+    fn rgb_to_lch_red() {
+        let rgb = RgbColor { r: 255, g: 0, b: 0 };
+        let lch = LchColor::from(rgb);
+        assert_float_absolute_eq!(lch.l, 53.24, 0.01);
+        assert_float_absolute_eq!(lch.c, 104.55, 0.01);
+        assert_float_absolute_eq!(lch.h, 40.0, 0.01);
+    }
+
+    #[test]
+    fn hsl_to_lch_blue() {
+        let hsl = HslColor::new(240, 100, 50);
+        let lch = LchColor::from(hsl);
+        assert_float_absolute_eq!(lch.l, 32.30, 0.01);
+        assert_float_absolute_eq!(lch.c, 133.81, 0.01);
+        assert_float_absolute_eq!(lch.h, 306.29, 0.01);
+    }
+
+}
@@ -0,0 +1,109 @@
+use std::convert::From;
+use crate::hsl_color::HslColor;
+use crate::rgb_color::RgbColor;
+use crate::oklch_color::OklchColor;
+use crate::linear_rgb::srgb_to_linear;
+
+impl From<RgbColor> for OklchColor {    
+    fn from(RgbColor { r, g, b }: RgbColor) -> Self {
+        let r = f32::from(r) / 255.0;
+        let g = f32::from(g) / 255.0;
+        let b = f32::from(b) / 255.0;
+        OklchColor::convert_rgb_f32_to_oklch([r, g, b])
+    }
+}
+
+impl From<HslColor> for OklchColor {    
+    fn from(hsl: HslColor) -> Self {
+        OklchColor::convert_rgb_f32_to_oklch(hsl.convert_hsl_to_rgb_f32())
+    }
+}
+
+/// Linear sRGB to OKLab (`[L, a, b]`), by Björn Ottosson
+#[allow(clippy::excessive_precision)]
+pub(crate) fn convert_linear_rgb_to_oklab([r, g, b]: [f32; 3]) -> [f32; 3] {
+
+    let l = 0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b;
+    let m = 0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b;
+    let s = 0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b;
+
+    let l = l.cbrt();
+    let m = m.cbrt();
+    let s = s.cbrt();
+
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ]
+}
+
+impl OklchColor {
+
+    /// Unquantized conversion, channels should be in 0..=1 range
+    pub(crate) fn convert_rgb_f32_to_oklch([r, g, b]: [f32; 3]) -> Self {
+
+        let linear = [srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b)];
+        let [l, a, b] = convert_linear_rgb_to_oklab(linear);
+
+        let c = f32::sqrt(a * a + b * b);
+        let h = if c < 1e-4 {
+            0.0
+        } else {
+            let h = f32::atan2(b, a).to_degrees();
+            if h < 0.0 { h + 360.0 } else { h }
+        };
+
+        OklchColor { l, c, h }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    #[test]
+    fn rgb_to_oklch_white() {
+        let rgb = RgbColor { r: 255, g: 255, b: 255 };
+        let oklch = OklchColor::from(rgb);
+        assert_float_absolute_eq!(oklch.l, 1.0, 1e-3);
+        assert_float_absolute_eq!(oklch.c, 0.0, 1e-3);
+    }
+
+    #[test]
+    fn rgb_to_oklch_black() {
+        let rgb = RgbColor { r: 0, g: 0, b: 0 };
+        let oklch = OklchColor::from(rgb);
+        assert_float_absolute_eq!(oklch.l, 0.0, 1e-3);
+        assert_float_absolute_eq!(oklch.c, 0.0, 1e-3);
+    }
+
+    #[test]
+    fn rgb_to_oklch_red() {
+        let rgb = RgbColor { r: 255, g: 0, b: 0 };
+        let oklch = OklchColor::from(rgb);
+        assert_float_absolute_eq!(oklch.l, 0.628, 1e-3);
+        assert_float_absolute_eq!(oklch.c, 0.258, 1e-3);
+        assert_float_absolute_eq!(oklch.h, 29.23, 0.1);
+    }
+
+    #[test]
+    fn rgb_to_oklch_blue() {
+        let rgb = RgbColor { r: 0, g: 0, b: 255 };
+        let oklch = OklchColor::from(rgb);
+        assert_float_absolute_eq!(oklch.l, 0.452, 1e-3);
+        assert_float_absolute_eq!(oklch.c, 0.313, 1e-3);
+        assert_float_absolute_eq!(oklch.h, 264.05, 0.1);
+    }
+
+    #[test]
+    fn hsl_to_oklch_green() {
+        let hsl = HslColor::new(120, 100, 50);
+        let oklch = OklchColor::from(hsl);
+        assert_float_absolute_eq!(oklch.l, 0.866, 1e-3);
+        assert_float_absolute_eq!(oklch.c, 0.295, 1e-3);
+        assert_float_absolute_eq!(oklch.h, 142.5, 0.1);
+    }
+
+}
@@ -0,0 +1,35 @@
+use std::convert::From;
+use crate::hsl_color::HslColor;
+use crate::rgb_color::RgbColor;
+use crate::hsla_color::HslaColor;
+use crate::rgba_color::RgbaColor;
+
+impl From<RgbaColor> for HslaColor {
+    fn from(val: RgbaColor) -> Self {
+        let hsl = HslColor::from(RgbColor::from(val));
+        HslaColor::from_hsl(hsl, f32::from(val.a) / 255.0 * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    #[test]
+    fn rgba_to_hsla_translucent_green() {
+        let rgba = RgbaColor { r: 0, g: 255, b: 0, a: 51 };
+        let hsla = HslaColor::from(rgba);
+        assert_f32_near!(hsla.h, 120.0, 99999);
+        assert_f32_near!(hsla.l, 50.0, 99999);
+        assert_f32_near!(hsla.a, 20.0, 99999);
+    }
+
+    #[test]
+    fn rgba_to_hsla_roundtrip() {
+        let original = RgbaColor { r: 30, g: 144, b: 200, a: 77 };
+        let rgba: RgbaColor = HslaColor::from(original).into();
+        assert_eq!(rgba, original);
+    }
+
+}
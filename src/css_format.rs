@@ -0,0 +1,183 @@
+use std::fmt;
+use crate::hsl_color::HslColor;
+use crate::rgb_color::RgbColor;
+use crate::oklch_color::OklchColor;
+
+/// CSS serialization formats, see `to_css()`
+/// on [`RgbColor`](RgbColor) and [`HslColor`](HslColor)
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[non_exhaustive]
+pub enum CssFormat {
+    /// `#rrggbb`
+    #[default]
+    Hex,
+    /// `#rgb` when lossless, `#rrggbb` otherwise
+    HexShort,
+    /// `rgb(r g b)`, channels are 0..=255
+    Rgb,
+    /// `hsl(h s% l%)`, with the number of decimal places
+    Hsl { precision: usize },
+    /// `oklch(l c h)`, with the number of decimal places
+    Oklch { precision: usize },
+}
+
+fn format_number(value: f32, precision: Option<usize>) -> String {
+
+    let text = match precision {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => format!("{}", value),
+    };
+
+    if text.starts_with('-') && text[1..].chars().all(|c| c == '0' || c == '.') {
+        text[1..].to_string()
+    } else {
+        text
+    }
+}
+
+fn format_hsl(color: HslColor, precision: Option<usize>) -> String {
+    format!(
+        "hsl({} {}% {}%)",
+        format_number(color.h.rem_euclid(360.0), precision),
+        format_number(color.s, precision),
+        format_number(color.l, precision),
+    )
+}
+
+fn format_oklch(color: OklchColor, precision: usize) -> String {
+    format!(
+        "oklch({} {} {})",
+        format_number(color.l, Some(precision)),
+        format_number(color.c, Some(precision)),
+        format_number(color.h, Some(precision)),
+    )
+}
+
+impl RgbColor {
+
+    /// Serialize color as CSS string
+    pub fn to_css(&self, format: CssFormat) -> String {
+
+        let RgbColor { r, g, b } = *self;
+        let rgb_f32 = [r, g, b].map(|channel| f32::from(channel) / 255.0);
+
+        match format {
+            CssFormat::Hex => format!("#{:02x}{:02x}{:02x}", r, g, b),
+            CssFormat::HexShort => {
+                if [r, g, b].iter().all(|channel| channel >> 4 == channel & 0x0F) {
+                    format!("#{:x}{:x}{:x}", r & 0x0F, g & 0x0F, b & 0x0F)
+                } else {
+                    self.to_css(CssFormat::Hex)
+                }
+            },
+            CssFormat::Rgb => format!("rgb({} {} {})", r, g, b),
+            CssFormat::Hsl { precision } => {
+                format_hsl(HslColor::convert_rgb_f32_to_hsl(rgb_f32), Some(precision))
+            },
+            CssFormat::Oklch { precision } => {
+                format_oklch(OklchColor::convert_rgb_f32_to_oklch(rgb_f32), precision)
+            },
+        }
+    }
+
+}
+
+impl HslColor {
+
+    /// Serialize color as CSS string, 
+    /// every format is quantized to 8-bit channels first,
+    /// so they all describe the same [`RgbColor`](RgbColor)
+    pub fn to_css(&self, format: CssFormat) -> String {
+        RgbColor::from(*self).to_css(format)
+    }
+
+}
+
+/// Format as `#rrggbb`
+impl fmt::Display for RgbColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_css(CssFormat::Hex))
+    }
+}
+
+/// Format as `hsl(h s% l%)`,
+/// the precision of the formatter sets the decimal places, e.g. `{:.1}`
+impl fmt::Display for HslColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_hsl(*self, f.precision()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn css_format_rgb() {
+        let rgb = RgbColor { r: 0x1A, g: 0xF9, b: 0xCC };
+        assert_eq!(rgb.to_css(CssFormat::Hex), "#1af9cc");
+        assert_eq!(rgb.to_css(CssFormat::HexShort), "#1af9cc");
+        assert_eq!(rgb.to_css(CssFormat::Rgb), "rgb(26 249 204)");
+        assert_eq!(format!("{}", rgb), "#1af9cc");
+
+        let rgb = RgbColor { r: 0xCC, g: 0x33, b: 0x00 };
+        assert_eq!(rgb.to_css(CssFormat::HexShort), "#c30");
+        assert_eq!(rgb.to_css(CssFormat::Hsl { precision: 1 }), "hsl(15.0 100.0% 40.0%)");
+    }
+
+    #[test]
+    fn css_format_hsl() {
+        let hsl = HslColor { h: -90.0, s: 50.0, l: 25.5 };
+        assert_eq!(format!("{}", hsl), "hsl(270 50% 25.5%)");
+        assert_eq!(format!("{:.2}", hsl), "hsl(270.00 50.00% 25.50%)");
+        assert_eq!(hsl.to_css(CssFormat::Hsl { precision: 0 }), "hsl(270 50% 26%)");
+    }
+
+    #[test]
+    fn css_format_hsl_same_color_as_hex() {
+        let hsl = HslColor::new(0, 0, 3);
+        assert_eq!(hsl.to_css(CssFormat::Hex), "#080808");
+        let text = hsl.to_css(CssFormat::Hsl { precision: 1 });
+        assert_eq!(RgbColor::from_str(&text).unwrap(), RgbColor { r: 8, g: 8, b: 8 });
+    }
+
+    #[test]
+    fn css_format_oklch() {
+        let rgb = RgbColor { r: 255, g: 0, b: 0 };
+        assert_eq!(rgb.to_css(CssFormat::Oklch { precision: 3 }), "oklch(0.628 0.258 29.234)");
+        let rgb = RgbColor { r: 255, g: 255, b: 255 };
+        assert_eq!(rgb.to_css(CssFormat::Oklch { precision: 2 }), "oklch(1.00 0.00 0.00)");
+    }
+
+    #[test]
+    fn css_format_roundtrip() {
+
+        let formats = [
+            CssFormat::Hex,
+            CssFormat::HexShort,
+            CssFormat::Rgb,
+            CssFormat::Hsl { precision: 2 },
+            CssFormat::Oklch { precision: 5 },
+        ];
+
+        for r in (0..=255).step_by(17) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(51) {
+                    let rgb = RgbColor { r, g, b };
+                    for format in formats {
+                        let text = rgb.to_css(format);
+                        assert_eq!(RgbColor::from_str(&text).unwrap(), rgb, "{}", text);
+                    }
+                    let hsl = HslColor::from(rgb);
+                    for format in formats {
+                        let text = hsl.to_css(format);
+                        assert_eq!(RgbColor::from_str(&text).unwrap(), rgb, "{}", text);
+                    }
+                    assert_eq!(RgbColor::from_str(&format!("{}", hsl)).unwrap(), rgb);
+                }
+            }
+        }
+    }
+
+}
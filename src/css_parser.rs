@@ -0,0 +1,434 @@
+use crate::hsl_color::HslColor;
+use crate::lab_color::LabColor;
+use crate::oklch_color::OklchColor;
+use crate::rgb_color::{ RgbColor, ParseError };
+use crate::css_named_colors::find_named_color;
+use crate::convert_oklch_to_rgb::convert_oklab_to_linear_rgb;
+use crate::linear_rgb::linear_to_srgb;
+
+/// Parse CSS Color Level 4 color, result is `[r, g, b, alpha]`,
+/// all channels are in 0..=1 range;
+/// byte offsets in errors are relative to the original string
+pub(crate) fn parse_css_color(text: &str) -> Result<[f32; 4], ParseError> {
+    CssParser::new(text).parse()
+}
+
+/// Valid CSS color functions, which are not supported
+const UNSUPPORTED_FUNCTIONS: [&str; 6] = [
+    "color", "color-mix", "device-cmyk", "light-dark", "var", "contrast-color",
+];
+
+/// Valid CSS color keywords, which can not be resolved without context
+const UNSUPPORTED_KEYWORDS: [&str; 6] = [
+    "currentcolor", "inherit", "initial", "unset", "revert", "revert-layer",
+];
+
+fn is_unsupported_function(name: &str) -> bool {
+    UNSUPPORTED_FUNCTIONS.iter().any(|function| function.eq_ignore_ascii_case(name))
+}
+
+fn is_unsupported_keyword(name: &str) -> bool {
+    UNSUPPORTED_KEYWORDS.iter().any(|keyword| keyword.eq_ignore_ascii_case(name))
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Component {
+    Number(f32),
+    Percentage(f32),
+    Degrees(f32),
+    None,
+}
+
+struct CssParser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> CssParser<'a> {
+
+    fn new(text: &'a str) -> Self {
+        Self { text, pos: 0 }
+    }
+
+    fn parse(&mut self) -> Result<[f32; 4], ParseError> {
+
+        self.skip_whitespace();
+        let start = self.pos;
+        let end = self.text.trim_end().len().max(start);
+
+        if self.peek() == Some(b'#') {
+            return Self::parse_hex(&self.text[start..end], start);
+        }
+
+        if !self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            return Self::parse_hex(&self.text[start..end], start);
+        }
+
+        let name = self.ident();
+        if self.peek() == Some(b'(') {
+            if is_unsupported_function(name) {
+                return Err(ParseError::UnsupportedFormat(start));
+            }
+            self.pos += 1;
+            let color = self.parse_function(name, start)?;
+            self.skip_whitespace();
+            return match self.peek() {
+                None => Ok(color),
+                Some(_) => Err(ParseError::UnexpectedCharacter(self.pos)),
+            };
+        }
+
+        self.skip_whitespace();
+        if self.pos < end {
+            return Err(ParseError::UnexpectedCharacter(self.pos));
+        }
+
+        if name.eq_ignore_ascii_case("transparent") {
+            return Ok([0.0, 0.0, 0.0, 0.0]);
+        }
+
+        if is_unsupported_keyword(name) {
+            return Err(ParseError::UnsupportedFormat(start));
+        }
+
+        if let Some(rgb) = find_named_color(name) {
+            let [r, g, b] = rgb.map(|channel| f32::from(channel) / 255.0);
+            return Ok([r, g, b, 1.0]);
+        }
+
+        // unprefixed hex, or hex-length name: report the hex error
+        if name.bytes().all(|c| c.is_ascii_hexdigit()) || matches!(name.len(), 3 | 4 | 6 | 8) {
+            return Self::parse_hex(name, start);
+        }
+
+        Err(ParseError::UnknownName(start))
+    }
+
+    fn parse_hex(hex: &str, offset: usize) -> Result<[f32; 4], ParseError> {
+        let rgba = RgbColor::try_parse_hex_with_alpha(hex).map_err(|err| err.with_offset(offset))?;
+        Ok(rgba.map(|channel| f32::from(channel) / 255.0))
+    }
+
+    fn parse_function(&mut self, name: &str, start: usize) -> Result<[f32; 4], ParseError> {
+
+        let name = name.to_ascii_lowercase();
+        let legacy_allowed = matches!(name.as_str(), "rgb" | "rgba" | "hsl" | "hsla");
+        let (channels, alpha) = self.arguments(legacy_allowed)?;
+        let [c0, c1, c2] = channels;
+
+        let alpha = match alpha {
+            Some(alpha) => Self::number_or_percent(alpha, 1.0)?.clamp(0.0, 1.0),
+            None => 1.0,
+        };
+
+        let [r, g, b] = match name.as_str() {
+            "rgb" | "rgba" => [
+                Self::number_or_percent(c0, 255.0)? / 255.0,
+                Self::number_or_percent(c1, 255.0)? / 255.0,
+                Self::number_or_percent(c2, 255.0)? / 255.0,
+            ],
+            "hsl" | "hsla" => HslColor {
+                h: Self::hue(c0)?.rem_euclid(360.0),
+                s: Self::number_or_percent(c1, 100.0)?.clamp(0.0, 100.0),
+                l: Self::number_or_percent(c2, 100.0)?.clamp(0.0, 100.0),
+            }.convert_hsl_to_rgb_f32(),
+            "hwb" => Self::hwb_to_rgb_f32(
+                Self::hue(c0)?,
+                Self::number_or_percent(c1, 100.0)?.clamp(0.0, 100.0) / 100.0,
+                Self::number_or_percent(c2, 100.0)?.clamp(0.0, 100.0) / 100.0,
+            ),
+            "lab" => LabColor {
+                l: Self::number_or_percent(c0, 100.0)?.clamp(0.0, 100.0),
+                a: Self::number_or_percent(c1, 125.0)?,
+                b: Self::number_or_percent(c2, 125.0)?,
+            }.convert_lab_to_rgb_f32(),
+            "lch" => {
+                let l = Self::number_or_percent(c0, 100.0)?.clamp(0.0, 100.0);
+                let c = Self::number_or_percent(c1, 150.0)?.max(0.0);
+                let h = Self::hue(c2)?.to_radians();
+                LabColor { l, a: c * h.cos(), b: c * h.sin() }.convert_lab_to_rgb_f32()
+            },
+            "oklab" => {
+                let l = Self::number_or_percent(c0, 1.0)?.clamp(0.0, 1.0);
+                let a = Self::number_or_percent(c1, 0.4)?;
+                let b = Self::number_or_percent(c2, 0.4)?;
+                convert_oklab_to_linear_rgb([l, a, b])
+                    .map(|value| linear_to_srgb(value.clamp(0.0, 1.0)))
+            },
+            "oklch" => OklchColor {
+                l: Self::number_or_percent(c0, 1.0)?.clamp(0.0, 1.0),
+                c: Self::number_or_percent(c1, 0.4)?.max(0.0),
+                h: Self::hue(c2)?,
+            }.convert_oklch_to_rgb_f32(),
+            _ => return Err(ParseError::UnknownName(start)),
+        };
+
+        Ok([r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0), alpha])
+    }
+
+    fn hwb_to_rgb_f32(h: f32, w: f32, b: f32) -> [f32; 3] {
+
+        if w + b >= 1.0 {
+            let gray = w / (w + b);
+            return [gray, gray, gray];
+        }
+
+        let pure = HslColor { h: h.rem_euclid(360.0), s: 100.0, l: 50.0 }.convert_hsl_to_rgb_f32();
+        pure.map(|channel| channel * (1.0 - w - b) + w)
+    }
+
+    /// Parse arguments after the opening parenthesis, including the closing one:
+    /// - legacy: `a, b, c` or `a, b, c, alpha`
+    /// - modern: `a b c` or `a b c / alpha`
+    #[allow(clippy::type_complexity)]
+    fn arguments(&mut self, legacy_allowed: bool) -> Result<([(Component, usize); 3], Option<(Component, usize)>), ParseError> {
+
+        let first = self.component()?;
+        self.skip_whitespace();
+
+        let mut channels = vec![first];
+        let mut alpha = None;
+
+        if self.peek() == Some(b',') {
+
+            if !legacy_allowed {
+                return Err(ParseError::UnexpectedCharacter(self.pos));
+            }
+            while self.peek() == Some(b',') {
+                self.pos += 1;
+                let component = self.component()?;
+                if matches!(component.0, Component::None) {
+                    return Err(ParseError::InvalidValue(component.1));
+                }
+                channels.push(component);
+                self.skip_whitespace();
+            }
+            if channels.len() == 4 {
+                alpha = channels.pop();
+            }
+
+        } else {
+
+            while channels.len() < 3 {
+                channels.push(self.component()?);
+                self.skip_whitespace();
+            }
+            if self.peek() == Some(b'/') {
+                self.pos += 1;
+                alpha = Some(self.component()?);
+                self.skip_whitespace();
+            }
+
+        }
+
+        match self.peek() {
+            Some(b')') if channels.len() == 3 => self.pos += 1,
+            Some(_) => return Err(ParseError::UnexpectedCharacter(self.pos)),
+            None => return Err(ParseError::UnexpectedEnd(self.pos)),
+        }
+
+        Ok(([channels[0], channels[1], channels[2]], alpha))
+    }
+
+    /// Parse number with optional unit, or `none` keyword
+    fn component(&mut self) -> Result<(Component, usize), ParseError> {
+
+        self.skip_whitespace();
+        let start = self.pos;
+
+        if self.peek().is_none() {
+            return Err(ParseError::UnexpectedEnd(start));
+        }
+
+        if self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            let keyword = self.ident();
+            if keyword.eq_ignore_ascii_case("none") {
+                return Ok((Component::None, start));
+            }
+            return Err(ParseError::InvalidValue(start));
+        }
+
+        let value = self.number()?;
+
+        if self.peek() == Some(b'%') {
+            self.pos += 1;
+            return Ok((Component::Percentage(value), start));
+        }
+
+        if !self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            return Ok((Component::Number(value), start));
+        }
+
+        let unit_start = self.pos;
+        let degrees = match self.ident().to_ascii_lowercase().as_str() {
+            "deg" => value,
+            "grad" => value * 0.9,
+            "rad" => value.to_degrees(),
+            "turn" => value * 360.0,
+            _ => return Err(ParseError::InvalidValue(unit_start)),
+        };
+
+        Ok((Component::Degrees(degrees), start))
+    }
+
+    fn number(&mut self) -> Result<f32, ParseError> {
+
+        let start = self.pos;
+        let bytes = self.text.as_bytes();
+        let mut end = start;
+
+        if matches!(bytes.get(end), Some(b'+' | b'-')) {
+            end += 1;
+        }
+        while bytes.get(end).is_some_and(|c| c.is_ascii_digit() || *c == b'.') {
+            end += 1;
+        }
+        if matches!(bytes.get(end), Some(b'e' | b'E')) {
+            let mut exp = end + 1;
+            if matches!(bytes.get(exp), Some(b'+' | b'-')) {
+                exp += 1;
+            }
+            if bytes.get(exp).is_some_and(|c| c.is_ascii_digit()) {
+                end = exp;
+                while bytes.get(end).is_some_and(|c| c.is_ascii_digit()) {
+                    end += 1;
+                }
+            }
+        }
+
+        let value = self.text[start..end]
+            .parse::<f32>()
+            .map_err(|_| ParseError::InvalidValue(start))?;
+        self.pos = end;
+
+        Ok(value)
+    }
+
+    fn number_or_percent((component, pos): (Component, usize), percent_base: f32) -> Result<f32, ParseError> {
+        match component {
+            Component::Number(value) => Ok(value),
+            Component::Percentage(value) => Ok(value * percent_base / 100.0),
+            Component::None => Ok(0.0),
+            Component::Degrees(_) => Err(ParseError::InvalidValue(pos)),
+        }
+    }
+
+    fn hue((component, pos): (Component, usize)) -> Result<f32, ParseError> {
+        match component {
+            Component::Number(value) | Component::Degrees(value) => Ok(value),
+            Component::None => Ok(0.0),
+            Component::Percentage(_) => Err(ParseError::InvalidValue(pos)),
+        }
+    }
+
+    fn ident(&mut self) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == b'-') {
+            self.pos += 1;
+        }
+        &self.text[start..self.pos]
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use crate::RgbaColor;
+
+    fn rgba(text: &str) -> RgbaColor {
+        RgbaColor::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn css_hex_and_names() {
+        assert_eq!(rgba("#1af9cc"), RgbaColor { r: 0x1A, g: 0xF9, b: 0xCC, a: 255 });
+        assert_eq!(rgba("  #C348 "), RgbaColor { r: 0xCC, g: 0x33, b: 0x44, a: 0x88 });
+        assert_eq!(rgba("abc"), RgbaColor { r: 0xAA, g: 0xBB, b: 0xCC, a: 255 });
+        assert_eq!(rgba("RebeccaPurple"), RgbaColor { r: 0x66, g: 0x33, b: 0x99, a: 255 });
+        assert_eq!(rgba("transparent"), RgbaColor { r: 0, g: 0, b: 0, a: 0 });
+    }
+
+    #[test]
+    fn css_rgb_legacy_and_modern() {
+        let expected = RgbaColor { r: 255, g: 128, b: 0, a: 255 };
+        assert_eq!(rgba("rgb(255, 128, 0)"), expected);
+        assert_eq!(rgba("rgb(255 128 0)"), expected);
+        assert_eq!(rgba("RGB( 100% 50.2% 0% )"), expected);
+        assert_eq!(rgba("rgba(255, 128, 0, 0.5)"), RgbaColor { a: 128, ..expected });
+        assert_eq!(rgba("rgb(255 128 0 / 50%)"), RgbaColor { a: 128, ..expected });
+        assert_eq!(rgba("rgb(255 none 0)"), RgbaColor { g: 0, ..expected });
+        assert_eq!(rgba("rgb(2.55e2 128 0)"), expected);
+    }
+
+    #[test]
+    fn css_hsl_angle_units() {
+        let expected = RgbaColor { r: 0, g: 0, b: 255, a: 255 };
+        assert_eq!(rgba("hsl(240, 100%, 50%)"), expected);
+        assert_eq!(rgba("hsl(240deg 100% 50%)"), expected);
+        assert_eq!(rgba("hsl(0.6666667turn 100 50)"), expected);
+        assert_eq!(rgba("hsl(4.18879rad 100% 50%)"), expected);
+        assert_eq!(rgba("hsla(266.6667grad, 100%, 50%, 1)"), expected);
+        assert_eq!(rgba("hsl(-120 100% 50%)"), expected);
+    }
+
+    #[test]
+    fn css_hwb() {
+        assert_eq!(rgba("hwb(0 0% 0%)"), RgbaColor { r: 255, g: 0, b: 0, a: 255 });
+        assert_eq!(rgba("hwb(120 20% 20%)"), RgbaColor { r: 51, g: 204, b: 51, a: 255 });
+        assert_eq!(rgba("hwb(0 60% 60%)"), RgbaColor { r: 128, g: 128, b: 128, a: 255 });
+    }
+
+    #[test]
+    fn css_lab_lch_oklab_oklch() {
+        let white = RgbaColor { r: 255, g: 255, b: 255, a: 255 };
+        assert_eq!(rgba("lab(100 0 0)"), white);
+        assert_eq!(rgba("lch(100% 0 0)"), white);
+        assert_eq!(rgba("oklab(1 0 0)"), white);
+        assert_eq!(rgba("oklch(100% 0 0)"), white);
+
+        let red = RgbaColor { r: 255, g: 0, b: 0, a: 255 };
+        assert_eq!(rgba("lab(53.24 80.09 67.2)"), red);
+        assert_eq!(rgba("lch(53.24 104.55 40)"), red);
+        assert_eq!(rgba("oklab(0.628 0.2249 0.1258)"), red);
+        assert_eq!(rgba("oklch(62.8% 0.2577 29.23 / 0.25)"), RgbaColor { a: 64, ..red });
+    }
+
+    #[test]
+    fn css_error_offsets() {
+        assert!(matches!(RgbaColor::from_str("blueish"), Err(ParseError::UnknownName(0))));
+        assert!(matches!(RgbaColor::from_str("bluish"), Err(ParseError::InvalidDigit(b'l', 1))));
+        assert!(matches!(RgbaColor::from_str("C34G"), Err(ParseError::InvalidDigit(b'G', 3))));
+        assert!(matches!(RgbaColor::from_str(" c3g"), Err(ParseError::InvalidDigit(b'g', 3))));
+        assert!(matches!(RgbaColor::from_str("abcde"), Err(ParseError::InvalidLength(5))));
+        assert!(matches!(RgbaColor::from_str(" foo(1 2 3)"), Err(ParseError::UnknownName(1))));
+        assert!(matches!(RgbaColor::from_str("rgb(1 2px 3)"), Err(ParseError::InvalidValue(7))));
+        assert!(matches!(RgbaColor::from_str("hsl(10% 2% 3%)"), Err(ParseError::InvalidValue(4))));
+        assert!(matches!(RgbaColor::from_str("rgb(1, 2 3)"), Err(ParseError::UnexpectedCharacter(9))));
+        assert!(matches!(RgbaColor::from_str("lab(1, 2, 3)"), Err(ParseError::UnexpectedCharacter(5))));
+        assert!(matches!(RgbaColor::from_str("rgb(1 2 3) x"), Err(ParseError::UnexpectedCharacter(11))));
+        assert!(matches!(RgbaColor::from_str("rgb(1 2"), Err(ParseError::UnexpectedEnd(7))));
+        assert!(matches!(RgbaColor::from_str("rgb(1, 2)"), Err(ParseError::UnexpectedCharacter(8))));
+        assert!(matches!(RgbaColor::from_str("rgb(1 2 3"), Err(ParseError::UnexpectedEnd(9))));
+        assert!(matches!(RgbaColor::from_str("  #12x"), Err(ParseError::InvalidDigit(b'x', 5))));
+    }
+
+    #[test]
+    fn css_unsupported_formats() {
+        assert!(matches!(RgbaColor::from_str("color(display-p3 1 0 0)"), Err(ParseError::UnsupportedFormat(0))));
+        assert!(matches!(RgbaColor::from_str(" color-mix(in srgb, red, blue)"), Err(ParseError::UnsupportedFormat(1))));
+        assert!(matches!(RgbaColor::from_str("currentColor"), Err(ParseError::UnsupportedFormat(0))));
+    }
+
+}
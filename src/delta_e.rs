@@ -0,0 +1,275 @@
+use crate::hsl_color::HslColor;
+use crate::rgb_color::RgbColor;
+use crate::lab_color::LabColor;
+use crate::linear_rgb::srgb_to_linear;
+use crate::convert_rgb_to_oklch::convert_linear_rgb_to_oklab;
+
+/// Color difference metrics
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum DeltaE {
+    /// CIE 1976, Euclidean distance in CIELAB
+    Cie76,
+    /// CIE 1994, graphic arts weighting
+    Cie94,
+    /// CIEDE2000
+    Ciede2000,
+    /// Euclidean distance in OKLab, 
+    /// on its own scale (about 0.02 is a just noticeable difference)
+    Oklab,
+}
+
+impl DeltaE {
+
+    /// Difference of two gamma-encoded sRGB colors (channels 0..=1)
+    pub(crate) fn between_rgb_f32(self, a: [f32; 3], b: [f32; 3]) -> f32 {
+
+        if self == DeltaE::Oklab {
+            let a = convert_linear_rgb_to_oklab(a.map(srgb_to_linear));
+            let b = convert_linear_rgb_to_oklab(b.map(srgb_to_linear));
+            let dl = a[0] - b[0];
+            let da = a[1] - b[1];
+            let db = a[2] - b[2];
+            return f32::sqrt(dl * dl + da * da + db * db);
+        }
+
+        let a = LabColor::convert_rgb_f32_to_lab(a);
+        let b = LabColor::convert_rgb_f32_to_lab(b);
+
+        match self {
+            DeltaE::Cie76 => a.delta_e_76(&b),
+            DeltaE::Cie94 => a.delta_e_94(&b),
+            _ => a.delta_e_2000(&b),
+        }
+    }
+
+}
+
+impl LabColor {
+
+    /// CIE76 color difference
+    pub fn delta_e_76(&self, other: &LabColor) -> f32 {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        f32::sqrt(dl * dl + da * da + db * db)
+    }
+
+    /// CIE94 color difference, graphic arts weighting,
+    /// this color is the reference
+    pub fn delta_e_94(&self, other: &LabColor) -> f32 {
+
+        const K1: f32 = 0.045;
+        const K2: f32 = 0.015;
+
+        let c1 = f32::sqrt(self.a * self.a + self.b * self.b);
+        let c2 = f32::sqrt(other.a * other.a + other.b * other.b);
+
+        let dl = self.l - other.l;
+        let dc = c1 - c2;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        let dh_squared = (da * da + db * db - dc * dc).max(0.0);
+
+        let sc = 1.0 + K1 * c1;
+        let sh = 1.0 + K2 * c1;
+
+        f32::sqrt(dl * dl + (dc / sc) * (dc / sc) + dh_squared / (sh * sh))
+    }
+
+    /// CIEDE2000 color difference
+    pub fn delta_e_2000(&self, other: &LabColor) -> f32 {
+
+        let (l1, a1, b1) = (self.l as f64, self.a as f64, self.b as f64);
+        let (l2, a2, b2) = (other.l as f64, other.a as f64, other.b as f64);
+
+        let c1 = f64::sqrt(a1 * a1 + b1 * b1);
+        let c2 = f64::sqrt(a2 * a2 + b2 * b2);
+        let c_mean_7 = ((c1 + c2) / 2.0).powi(7);
+        let g = 0.5 * (1.0 - f64::sqrt(c_mean_7 / (c_mean_7 + 25f64.powi(7))));
+
+        let a1 = a1 * (1.0 + g);
+        let a2 = a2 * (1.0 + g);
+        let c1 = f64::sqrt(a1 * a1 + b1 * b1);
+        let c2 = f64::sqrt(a2 * a2 + b2 * b2);
+
+        let hue = |a: f64, b: f64| {
+            if a == 0.0 && b == 0.0 {
+                0.0
+            } else {
+                let h = b.atan2(a).to_degrees();
+                if h < 0.0 { h + 360.0 } else { h }
+            }
+        };
+        let h1 = hue(a1, b1);
+        let h2 = hue(a2, b2);
+
+        let dl = l2 - l1;
+        let dc = c2 - c1;
+        let dh = if c1 * c2 == 0.0 {
+            0.0
+        } else if (h2 - h1).abs() <= 180.0 {
+            h2 - h1
+        } else if h2 - h1 > 180.0 {
+            h2 - h1 - 360.0
+        } else {
+            h2 - h1 + 360.0
+        };
+        let dh = 2.0 * f64::sqrt(c1 * c2) * (dh / 2.0).to_radians().sin();
+
+        let l_mean = (l1 + l2) / 2.0;
+        let c_mean = (c1 + c2) / 2.0;
+        let h_mean = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+
+        let t = 1.0 
+            - 0.17 * (h_mean - 30.0).to_radians().cos()
+            + 0.24 * (2.0 * h_mean).to_radians().cos()
+            + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+            - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos()
+        ;
+
+        let d_theta = 30.0 * f64::exp(-((h_mean - 275.0) / 25.0).powi(2));
+        let c_mean_7 = c_mean.powi(7);
+        let rc = 2.0 * f64::sqrt(c_mean_7 / (c_mean_7 + 25f64.powi(7)));
+        let l_mean_50 = (l_mean - 50.0).powi(2);
+        let sl = 1.0 + 0.015 * l_mean_50 / f64::sqrt(20.0 + l_mean_50);
+        let sc = 1.0 + 0.045 * c_mean;
+        let sh = 1.0 + 0.015 * c_mean * t;
+        let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+
+        let l_term = dl / sl;
+        let c_term = dc / sc;
+        let h_term = dh / sh;
+
+        f64::sqrt(l_term * l_term + c_term * c_term + h_term * h_term + rt * c_term * h_term) as f32
+    }
+
+}
+
+impl RgbColor {
+
+    /// Color difference against other color, using the metric
+    pub fn delta_e<T>(&self, other: T, metric: DeltaE) -> f32 
+    where T: Into<RgbColor> {
+        let other: RgbColor = other.into();
+        let to_f32 = |color: RgbColor| [color.r, color.g, color.b].map(|value| f32::from(value) / 255.0);
+        metric.between_rgb_f32(to_f32(*self), to_f32(other))
+    }
+
+}
+
+impl HslColor {
+
+    /// Color difference against other color, using the metric
+    pub fn delta_e<T>(&self, other: T, metric: DeltaE) -> f32 
+    where T: Into<HslColor> {
+        let other: HslColor = other.into();
+        metric.between_rgb_f32(self.convert_hsl_to_rgb_f32(), other.convert_hsl_to_rgb_f32())
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    /// Sharma, Wu and Dalal (2005) CIEDE2000 test data
+    const SHARMA: [([f32; 3], [f32; 3], f32); 34] = [
+        ([50.0000, 2.6772, -79.7751], [50.0000, 0.0000, -82.7485], 2.0425),
+        ([50.0000, 3.1571, -77.2803], [50.0000, 0.0000, -82.7485], 2.8615),
+        ([50.0000, 2.8361, -74.0200], [50.0000, 0.0000, -82.7485], 3.4412),
+        ([50.0000, -1.3802, -84.2814], [50.0000, 0.0000, -82.7485], 1.0000),
+        ([50.0000, -1.1848, -84.8006], [50.0000, 0.0000, -82.7485], 1.0000),
+        ([50.0000, -0.9009, -85.5211], [50.0000, 0.0000, -82.7485], 1.0000),
+        ([50.0000, 0.0000, 0.0000], [50.0000, -1.0000, 2.0000], 2.3669),
+        ([50.0000, -1.0000, 2.0000], [50.0000, 0.0000, 0.0000], 2.3669),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0009], 7.1792),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0010], 7.1792),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0011], 7.2195),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0012], 7.2195),
+        ([50.0000, -0.0010, 2.4900], [50.0000, 0.0009, -2.4900], 4.8045),
+        ([50.0000, -0.0010, 2.4900], [50.0000, 0.0010, -2.4900], 4.8045),
+        ([50.0000, -0.0010, 2.4900], [50.0000, 0.0011, -2.4900], 4.7461),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 0.0000, -2.5000], 4.3065),
+        ([50.0000, 2.5000, 0.0000], [73.0000, 25.0000, -18.0000], 27.1492),
+        ([50.0000, 2.5000, 0.0000], [61.0000, -5.0000, 29.0000], 22.8977),
+        ([50.0000, 2.5000, 0.0000], [56.0000, -27.0000, -3.0000], 31.9030),
+        ([50.0000, 2.5000, 0.0000], [58.0000, 24.0000, 15.0000], 19.4535),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.1736, 0.5854], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.2972, 0.0000], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 1.8634, 0.5757], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.2592, 0.3350], 1.0000),
+        ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.2644),
+        ([63.0109, -31.0961, -5.8663], [62.8187, -29.7946, -4.0864], 1.2630),
+        ([61.2901, 3.7196, -5.3901], [61.4292, 2.2480, -4.9620], 1.8731),
+        ([35.0831, -44.1164, 3.7933], [35.0232, -40.0716, 1.5901], 1.8645),
+        ([22.7233, 20.0904, -46.6940], [23.0331, 14.9730, -42.5619], 2.0373),
+        ([36.4612, 47.8580, 18.3852], [36.2715, 50.5065, 21.2231], 1.4146),
+        ([90.8027, -2.0831, 1.4410], [91.1528, -1.6435, 0.0447], 1.4441),
+        ([90.9257, -0.5406, -0.9208], [88.6381, -0.8985, -0.7239], 1.5381),
+        ([6.7747, -0.2908, -2.4247], [5.8714, -0.0985, -2.2286], 0.6377),
+        ([2.0776, 0.0795, -1.1350], [0.9033, -0.0636, -0.5514], 0.9082),
+    ];
+
+    #[test]
+    fn delta_e_2000_sharma() {
+        for (index, (lab1, lab2, expected)) in SHARMA.iter().enumerate() {
+            let lab1 = LabColor::from(*lab1);
+            let lab2 = LabColor::from(*lab2);
+            let result = lab1.delta_e_2000(&lab2);
+            assert!((result - expected).abs() < 1e-3, "pair {}: {} != {}", index + 1, result, expected);
+            let result = lab2.delta_e_2000(&lab1);
+            assert!((result - expected).abs() < 1e-3, "pair {} reversed: {} != {}", index + 1, result, expected);
+        }
+    }
+
+    #[test]
+    fn delta_e_76() {
+        let lab1 = LabColor::new(50.0, 2.6772, -79.7751);
+        let lab2 = LabColor::new(50.0, 0.0, -82.7485);
+        assert_float_absolute_eq!(lab1.delta_e_76(&lab2), 4.0011, 1e-3);
+    }
+
+    #[test]
+    fn delta_e_94() {
+        let lab1 = LabColor::new(50.0, 2.6772, -79.7751);
+        let lab2 = LabColor::new(50.0, 0.0, -82.7485);
+        assert_float_absolute_eq!(lab1.delta_e_94(&lab2), 1.3950, 1e-3);
+    }
+
+    #[test]
+    fn delta_e_same_color() {
+        let color = RgbColor { r: 30, g: 144, b: 200 };
+        for metric in [DeltaE::Cie76, DeltaE::Cie94, DeltaE::Ciede2000, DeltaE::Oklab] {
+            assert_float_absolute_eq!(color.delta_e(color, metric), 0.0, 1e-5);
+        }
+    }
+
+    #[test]
+    fn delta_e_black_white() {
+        let black = RgbColor { r: 0, g: 0, b: 0 };
+        let white = RgbColor { r: 255, g: 255, b: 255 };
+        assert_float_absolute_eq!(black.delta_e(white, DeltaE::Cie76), 100.0, 0.01);
+        assert_float_absolute_eq!(black.delta_e(white, DeltaE::Ciede2000), 100.0, 0.01);
+        assert_float_absolute_eq!(black.delta_e(white, DeltaE::Oklab), 1.0, 0.001);
+    }
+
+    #[test]
+    fn delta_e_hsl_matches_rgb() {
+        let a = HslColor::new(0, 100, 50);
+        let b = HslColor::new(240, 100, 50);
+        let hsl_result = a.delta_e(b, DeltaE::Ciede2000);
+        let rgb_result = RgbColor::from(a).delta_e(RgbColor::from(b), DeltaE::Ciede2000);
+        assert_float_absolute_eq!(hsl_result, rgb_result, 0.01);
+    }
+
+}
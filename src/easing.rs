@@ -0,0 +1,137 @@
+/// Easing curves for the calculated spin modes
+/// (`Absolute`, `RelativeIncl`, `RelativeExcl`);
+/// the first and last values are the same as with linear spin,
+/// only the distribution of the steps between them changes
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Easing {
+    /// Constant step size (default)
+    #[default]
+    Linear,
+    /// Start slowly, same as CSS `ease-in`
+    EaseIn,
+    /// End slowly, same as CSS `ease-out`
+    EaseOut,
+    /// Start and end slowly, same as CSS `ease-in-out`
+    EaseInOut,
+    /// Cubic Bézier curve with control points `(x1, y1)` and `(x2, y2)`,
+    /// same as CSS `cubic-bezier(x1, y1, x2, y2)`
+    CubicBezier(f32, f32, f32, f32),
+    /// Hermite polynomial, starts and ends slowly
+    SmoothStep,
+    /// Exponential curve, positive exponent starts slowly,
+    /// negative exponent starts fast
+    Exponential(f32),
+}
+
+impl Easing {
+
+    /// Map progress (0..=1) to eased progress, 
+    /// keeping both ends in place
+    pub(crate) fn ease(self, t: f32) -> f32 {
+
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => Self::cubic_bezier(0.42, 0.0, 1.0, 1.0, t),
+            Easing::EaseOut => Self::cubic_bezier(0.0, 0.0, 0.58, 1.0, t),
+            Easing::EaseInOut => Self::cubic_bezier(0.42, 0.0, 0.58, 1.0, t),
+            Easing::CubicBezier(x1, y1, x2, y2) => Self::cubic_bezier(x1, y1, x2, y2, t),
+            Easing::SmoothStep => t * t * (3.0 - 2.0 * t),
+            Easing::Exponential(exponent) => {
+                if exponent.abs() < 1e-6 {
+                    t
+                } else {
+                    (f32::exp(exponent * t) - 1.0) / (f32::exp(exponent) - 1.0)
+                }
+            },
+        }
+    }
+
+    fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+
+        if x <= 0.0 || x >= 1.0 {
+            return x;
+        }
+
+        let x1 = x1.clamp(0.0, 1.0);
+        let x2 = x2.clamp(0.0, 1.0);
+
+        let curve = |p1: f32, p2: f32, t: f32| {
+            let u = 1.0 - t;
+            3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t
+        };
+
+        // x(t) is monotonic for control points in 0..=1, bisect it
+        let mut t_lo = 0.0;
+        let mut t_hi = 1.0;
+        let mut t = x;
+        for _ in 0..32 {
+            let value = curve(x1, x2, t);
+            if (value - x).abs() < 1e-6 {
+                break;
+            }
+            if value < x {
+                t_lo = t;
+            } else {
+                t_hi = t;
+            }
+            t = (t_lo + t_hi) / 2.0;
+        }
+
+        curve(y1, y2, t)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    #[test]
+    fn easing_keeps_ends() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::CubicBezier(0.1, 0.7, 1.0, 0.1),
+            Easing::SmoothStep,
+            Easing::Exponential(3.0),
+            Easing::Exponential(-3.0),
+        ] {
+            assert_float_absolute_eq!(easing.ease(0.0), 0.0, 1e-6);
+            assert_float_absolute_eq!(easing.ease(1.0), 1.0, 1e-6);
+        }
+    }
+
+    #[test]
+    fn easing_in_out_directions() {
+        assert!(Easing::EaseIn.ease(0.5) < 0.5);
+        assert!(Easing::EaseOut.ease(0.5) > 0.5);
+        assert!(Easing::Exponential(3.0).ease(0.5) < 0.5);
+        assert!(Easing::Exponential(-3.0).ease(0.5) > 0.5);
+    }
+
+    #[test]
+    fn easing_symmetric_midpoint() {
+        assert_float_absolute_eq!(Easing::EaseInOut.ease(0.5), 0.5, 1e-4);
+        assert_float_absolute_eq!(Easing::SmoothStep.ease(0.5), 0.5, 1e-6);
+    }
+
+    #[test]
+    fn easing_cubic_bezier_linear() {
+        let easing = Easing::CubicBezier(0.25, 0.25, 0.75, 0.75);
+        assert_float_absolute_eq!(easing.ease(0.3), 0.3, 1e-4);
+    }
+
+    #[test]
+    fn easing_css_ease_in_reference() {
+        // cubic-bezier(0.42, 0, 1, 1) at x = 0.5
+        assert_float_absolute_eq!(Easing::EaseIn.ease(0.5), 0.3153, 1e-3);
+    }
+
+}
@@ -2,6 +2,7 @@ use std::convert::From;
 
 /// HSL representation of a color
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HslColor {   
     /// - `h`: hue - degrees, 0..360, but it's okay to overflow/underflow
     pub h: f32, 
@@ -0,0 +1,169 @@
+use std::convert::From;
+use crate::hsl_color::HslColor;
+use crate::{ RgbColor, HsvColor, HwbColor, OklchColor, LabColor, LchColor };
+
+/// HSL representation of a color with alpha channel
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HslaColor {   
+    /// - `h`: hue - degrees, 0..360, but it's okay to overflow/underflow
+    pub h: f32, 
+    /// - `s`: saturation - percent, 0..=100
+    pub s: f32, 
+    /// - `l`: lightness - percent, 0..=100
+    pub l: f32,
+    /// - `a`: alpha (opacity) - percent, 0..=100
+    pub a: f32,
+}
+
+impl From<(f32, f32, f32, f32)> for HslaColor {
+    fn from((h, s, l, a): (f32, f32, f32, f32)) -> HslaColor {
+        HslaColor { h, s, l, a }
+    }
+}
+
+impl From<(i32, i32, i32, i32)> for HslaColor {
+    fn from((h, s, l, a): (i32, i32, i32, i32)) -> HslaColor {
+        HslaColor::new(h, s, l, a)
+    }
+}
+
+impl From<[i32; 4]> for HslaColor {
+    fn from(hsla: [i32; 4]) -> HslaColor {
+        HslaColor::new(hsla[0], hsla[1], hsla[2], hsla[3])
+    }
+}
+
+/// Opaque color
+impl From<HslColor> for HslaColor {
+    fn from(HslColor { h, s, l }: HslColor) -> HslaColor {
+        HslaColor { h, s, l, a: 100.0 }
+    }
+}
+
+/// Opaque color
+impl From<(f32, f32, f32)> for HslaColor {
+    fn from(hsl: (f32, f32, f32)) -> HslaColor {
+        HslaColor::from(HslColor::from(hsl))
+    }
+}
+
+/// Opaque color
+impl From<(i32, i32, i32)> for HslaColor {
+    fn from(hsl: (i32, i32, i32)) -> HslaColor {
+        HslaColor::from(HslColor::from(hsl))
+    }
+}
+
+/// Opaque color
+impl From<[i32; 3]> for HslaColor {
+    fn from(hsl: [i32; 3]) -> HslaColor {
+        HslaColor::from(HslColor::from(hsl))
+    }
+}
+
+/// Opaque color
+impl From<&[i32; 3]> for HslaColor {
+    fn from(hsl: &[i32; 3]) -> HslaColor {
+        HslaColor::from(HslColor::from(hsl))
+    }
+}
+
+/// Opaque color
+impl From<RgbColor> for HslaColor {
+    fn from(val: RgbColor) -> HslaColor {
+        HslaColor::from(HslColor::from(val))
+    }
+}
+
+/// Opaque color
+impl From<HsvColor> for HslaColor {
+    fn from(val: HsvColor) -> HslaColor {
+        HslaColor::from(HslColor::from(val))
+    }
+}
+
+/// Opaque color
+impl From<HwbColor> for HslaColor {
+    fn from(val: HwbColor) -> HslaColor {
+        HslaColor::from(HslColor::from(val))
+    }
+}
+
+/// Opaque color
+impl From<OklchColor> for HslaColor {
+    fn from(val: OklchColor) -> HslaColor {
+        HslaColor::from(HslColor::from(val))
+    }
+}
+
+/// Opaque color
+impl From<LabColor> for HslaColor {
+    fn from(val: LabColor) -> HslaColor {
+        HslaColor::from(HslColor::from(val))
+    }
+}
+
+/// Opaque color
+impl From<LchColor> for HslaColor {
+    fn from(val: LchColor) -> HslaColor {
+        HslaColor::from(HslColor::from(val))
+    }
+}
+
+/// Alpha channel is dropped
+impl From<HslaColor> for HslColor {
+    fn from(HslaColor { h, s, l, .. }: HslaColor) -> HslColor {
+        HslColor { h, s, l }
+    }
+}
+
+impl HslaColor {
+
+    /// Constructor with integer values, for simplicity
+    pub fn new(h: i32, s: i32, l: i32, a: i32) -> Self {                
+        Self {
+            h: h as f32, 
+            s: s as f32, 
+            l: l as f32,
+            a: a as f32,
+        }
+    }
+
+    /// Color with the alpha value, in percent
+    pub fn from_hsl(color: HslColor, a: f32) -> Self {
+        Self { h: color.h, s: color.s, l: color.l, a }
+    }
+
+    pub(crate) fn normalize(&mut self) {
+
+        let mut hsl = HslColor::from(*self);
+        hsl.normalize();
+        *self = Self::from_hsl(hsl, self.a.clamp(0.0, 100.0));
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    #[test]
+    fn hsla_from_hsl_opaque() {
+        let hsla = HslaColor::from(HslColor::new(120, 50, 40));
+        assert_f32_near!(hsla.a, 100.0);
+        assert_eq!(HslColor::from(hsla), HslColor::new(120, 50, 40));
+    }
+
+    #[test]
+    fn hsla_normalize() {
+        let mut hsla = HslaColor::new(370, 120, -10, 130);
+        hsla.normalize();
+        assert_f32_near!(hsla.h, 10.0, 99999);
+        assert_f32_near!(hsla.s, 100.0);
+        assert_f32_near!(hsla.l, 0.0);
+        assert_f32_near!(hsla.a, 100.0);
+    }
+
+}
@@ -0,0 +1,44 @@
+use std::convert::From;
+
+/// HSV (aka. HSB) representation of a color
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HsvColor {   
+    /// - `h`: hue - degrees, 0..360, but it's okay to overflow/underflow
+    pub h: f32, 
+    /// - `s`: saturation - percent, 0..=100
+    pub s: f32, 
+    /// - `v`: value (brightness) - percent, 0..=100
+    pub v: f32,
+}
+
+impl From<(f32, f32, f32)> for HsvColor {
+    fn from((h, s, v): (f32, f32, f32)) -> HsvColor {
+        HsvColor { h, s, v }
+    }
+}
+
+impl From<(i32, i32, i32)> for HsvColor {
+    fn from((h, s, v): (i32, i32, i32)) -> HsvColor {
+        HsvColor::new(h, s, v)
+    }
+}
+
+impl From<[i32; 3]> for HsvColor {
+    fn from(hsv: [i32; 3]) -> HsvColor {
+        HsvColor::new(hsv[0], hsv[1], hsv[2])
+    }
+}
+
+impl HsvColor {
+
+    /// Constructor with integer values, for simplicity
+    pub fn new(h: i32, s: i32, v: i32) -> Self {                
+        Self {
+            h: h as f32, 
+            s: s as f32, 
+            v: v as f32,
+        }
+    }
+
+}
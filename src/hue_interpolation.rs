@@ -0,0 +1,70 @@
+/// Direction of the hue spin towards an absolute target,
+/// same as CSS Color 4 hue interpolation methods
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum HueInterpolation {
+    /// Spin by the difference of the values as specified,
+    /// e.g. from 350° to 10° goes backwards through the whole wheel (default)
+    #[default]
+    Specified,
+    /// Take the shorter arc, at most 180°
+    Shorter,
+    /// Take the longer arc, at least 180°,
+    /// a full turn if the target equals the base
+    Longer,
+    /// Spin clockwise (hue increases)
+    Increasing,
+    /// Spin counter-clockwise (hue decreases)
+    Decreasing,
+}
+
+impl HueInterpolation {
+
+    /// Signed difference of hue to spin from `from` to `to`
+    pub(crate) fn delta(self, from: f32, to: f32) -> f32 {
+
+        let delta = to - from;
+        let positive = delta.rem_euclid(360.0);
+
+        match self {
+            HueInterpolation::Specified => delta,
+            HueInterpolation::Shorter if positive > 180.0 => positive - 360.0,
+            HueInterpolation::Shorter => positive,
+            HueInterpolation::Longer if positive == 0.0 => 360.0,
+            HueInterpolation::Longer if positive < 180.0 => positive - 360.0,
+            HueInterpolation::Longer => positive,
+            HueInterpolation::Increasing => positive,
+            HueInterpolation::Decreasing if positive > 0.0 => positive - 360.0,
+            HueInterpolation::Decreasing => 0.0,
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    #[test]
+    fn hue_interpolation_delta() {
+        assert_f32_near!(HueInterpolation::Specified.delta(350.0, 10.0), -340.0);
+        assert_f32_near!(HueInterpolation::Shorter.delta(350.0, 10.0), 20.0);
+        assert_f32_near!(HueInterpolation::Shorter.delta(10.0, 350.0), -20.0);
+        assert_f32_near!(HueInterpolation::Longer.delta(350.0, 10.0), -340.0);
+        assert_f32_near!(HueInterpolation::Longer.delta(10.0, 350.0), 340.0);
+        assert_f32_near!(HueInterpolation::Increasing.delta(10.0, 350.0), 340.0);
+        assert_f32_near!(HueInterpolation::Increasing.delta(350.0, 10.0), 20.0);
+        assert_f32_near!(HueInterpolation::Decreasing.delta(350.0, 10.0), -340.0);
+        assert_f32_near!(HueInterpolation::Decreasing.delta(10.0, 350.0), -20.0);
+    }
+
+    #[test]
+    fn hue_interpolation_same_hue() {
+        assert_f32_near!(HueInterpolation::Shorter.delta(120.0, 480.0), 0.0);
+        assert_f32_near!(HueInterpolation::Longer.delta(120.0, 120.0), 360.0);
+        assert_f32_near!(HueInterpolation::Decreasing.delta(120.0, 120.0), 0.0);
+    }
+
+}
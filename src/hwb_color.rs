@@ -0,0 +1,44 @@
+use std::convert::From;
+
+/// HWB representation of a color
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HwbColor {   
+    /// - `h`: hue - degrees, 0..360, but it's okay to overflow/underflow
+    pub h: f32, 
+    /// - `w`: whiteness - percent, 0..=100
+    pub w: f32, 
+    /// - `b`: blackness - percent, 0..=100
+    pub b: f32,
+}
+
+impl From<(f32, f32, f32)> for HwbColor {
+    fn from((h, w, b): (f32, f32, f32)) -> HwbColor {
+        HwbColor { h, w, b }
+    }
+}
+
+impl From<(i32, i32, i32)> for HwbColor {
+    fn from((h, w, b): (i32, i32, i32)) -> HwbColor {
+        HwbColor::new(h, w, b)
+    }
+}
+
+impl From<[i32; 3]> for HwbColor {
+    fn from(hwb: [i32; 3]) -> HwbColor {
+        HwbColor::new(hwb[0], hwb[1], hwb[2])
+    }
+}
+
+impl HwbColor {
+
+    /// Constructor with integer values, for simplicity
+    pub fn new(h: i32, w: i32, b: i32) -> Self {                
+        Self {
+            h: h as f32, 
+            w: w as f32, 
+            b: b as f32,
+        }
+    }
+
+}
@@ -0,0 +1,34 @@
+use std::convert::From;
+
+/// CIELAB representation of a color (D65 white point)
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LabColor {   
+    /// - `l`: lightness, 0..=100
+    pub l: f32, 
+    /// - `a`: green-red axis, roughly -128..128
+    pub a: f32, 
+    /// - `b`: blue-yellow axis, roughly -128..128
+    pub b: f32,
+}
+
+impl From<(f32, f32, f32)> for LabColor {
+    fn from((l, a, b): (f32, f32, f32)) -> LabColor {
+        LabColor { l, a, b }
+    }
+}
+
+impl From<[f32; 3]> for LabColor {
+    fn from([l, a, b]: [f32; 3]) -> LabColor {
+        LabColor { l, a, b }
+    }
+}
+
+impl LabColor {
+
+    /// Constructor with lightness and the two color axes
+    pub fn new(l: f32, a: f32, b: f32) -> Self {                
+        Self { l, a, b }
+    }
+
+}
@@ -0,0 +1,35 @@
+use std::convert::From;
+
+/// CIELCh representation of a color,
+/// the polar form of [`LabColor`](crate::LabColor) (D65 white point)
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LchColor {   
+    /// - `l`: lightness, 0..=100
+    pub l: f32, 
+    /// - `c`: chroma, 0..about 150
+    pub c: f32, 
+    /// - `h`: hue - degrees, 0..360, but it's okay to overflow/underflow
+    pub h: f32,
+}
+
+impl From<(f32, f32, f32)> for LchColor {
+    fn from((l, c, h): (f32, f32, f32)) -> LchColor {
+        LchColor { l, c, h }
+    }
+}
+
+impl From<[f32; 3]> for LchColor {
+    fn from([l, c, h]: [f32; 3]) -> LchColor {
+        LchColor { l, c, h }
+    }
+}
+
+impl LchColor {
+
+    /// Constructor with lightness, chroma and hue
+    pub fn new(l: f32, c: f32, h: f32) -> Self {                
+        Self { l, c, h }
+    }
+
+}
@@ -5,7 +5,7 @@ to make it easy
 to create palettes for GUI applications.
 
 > With a slightly different API, for a slightly different platform:
-[KolorWheel.js](https://github.com/ern0/kolorwheel.js/)
+> [KolorWheel.js](https://github.com/ern0/kolorwheel.js/)
 
 The way of creating a palette is 
 to specify a base color 
@@ -28,10 +28,86 @@ pub use hsl_color::HslColor;
 mod rgb_color;
 pub use rgb_color::RgbColor;
 
+mod hsla_color;
+pub use hsla_color::HslaColor;
+
+mod rgba_color;
+pub use rgba_color::RgbaColor;
+
+mod hsv_color;
+pub use hsv_color::HsvColor;
+
+mod hwb_color;
+pub use hwb_color::HwbColor;
+
+mod oklch_color;
+pub use oklch_color::OklchColor;
+
+mod lab_color;
+pub use lab_color::LabColor;
+
+mod lch_color;
+pub use lch_color::LchColor;
+
+mod spin_space;
+pub use spin_space::SpinSpace;
+
+mod easing;
+pub use easing::Easing;
+
+mod hue_interpolation;
+pub use hue_interpolation::HueInterpolation;
+
+mod boundary;
+pub use boundary::Boundary;
+
+mod wcag_contrast;
+pub use wcag_contrast::{ WcagLevel, TextSize, ContrastFallback };
+
+mod apca_contrast;
+pub use apca_contrast::ApcaPolarity;
+
+mod color_deficiency;
+pub use color_deficiency::ColorDeficiency;
+
+mod delta_e;
+pub use delta_e::DeltaE;
+
+mod palette_report;
+pub use palette_report::{ PaletteReport, PaletteAnalysis, ClosePair };
+
 pub use rgb_color::ParseError;
 
+mod css_parser;
+mod css_named_colors;
+
+mod css_format;
+pub use css_format::CssFormat;
+
+#[cfg(feature = "serde")]
+mod color_serde;
+
+mod recipe;
+pub use recipe::{ PaletteRecipe, LevelRecipe, SpinRecipe };
+
+mod palette_entry;
+pub use palette_entry::{ PaletteEntry, EntryIter };
+
+mod validation_error;
+pub use validation_error::ValidationError;
+use validation_error::{ validate_count, validate_color, validate_spin_mode };
+
 mod convert_hsl_to_rgb;
 mod convert_rgb_to_hsl;
+mod convert_hsla_to_rgba;
+mod convert_rgba_to_hsla;
+mod convert_hsl_to_hsv;
+mod convert_hsv_to_hsl;
+mod convert_oklch_to_rgb;
+mod convert_rgb_to_oklch;
+mod convert_lab_to_rgb;
+mod convert_rgb_to_lab;
+mod linear_rgb;
 mod spinner;
 
 use std::vec::Vec;
@@ -41,58 +117,199 @@ use crate::spinner::Spinner;
 /// [`HslColor`](HslColor) color objects upon spin
 pub struct KolorWheel {
     index: usize,
-    spinner_vec: Vec<Spinner>,
+    selected: Option<usize>,
+    level_vec: Vec<Level>,
+    offset_vec: Vec<Vec<usize>>,
+    path: Vec<usize>,
     level: usize,
     top_level: usize,
+    front: usize,
+    back: usize,
+    min_contrast: Option<(HslColor, f32)>,
+    contrast_fallback: ContrastFallback,
+    deficiency: Option<ColorDeficiency>,
+}
+
+/// Spinners of a level, one for each position of the parent
+/// in round-robin manner, or a single one shared by all parents
+struct Level {
+    spinner_vec: Vec<Spinner>,
+}
+
+impl Level {
+
+    fn new(spinner: Spinner) -> Self {
+        Self { spinner_vec: vec![spinner] }
+    }
+
+    fn spinner(&self, parent: usize) -> &Spinner {
+        &self.spinner_vec[parent % self.spinner_vec.len()]
+    }
+
+    fn spinner_mut(&mut self, parent: usize) -> &mut Spinner {
+        let len = self.spinner_vec.len();
+        &mut self.spinner_vec[parent % len]
+    }
+
+    fn max_count(&self) -> usize {
+        self.spinner_vec.iter().map(Spinner::count).max().unwrap_or(0)
+    }
+
 }
 
 /// Change modes to be applied on H/S/L channels
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
 pub enum SpinMode<'m> {
     /// Don't change the value (default)
     Still,
-    /// Set absolute goal value
+    /// Set absolute goal value;
+    /// with a single step (`count == 1`) the goal is not reached,
+    /// the only color emitted is the base color
     Absolute(i32),
-    /// Set relative goal value, the last step of the current round
+    /// Set relative goal value, the last step of the current round;
+    /// with a single step the base color is emitted, as for `Absolute`
     RelativeIncl(i32),
     /// Set relative goal value, the first step of the next round
     RelativeExcl(i32),
     /// List (slice) of values which the result will be offseted with,
     /// in round-robin fashion
     Offset(&'m [i32]),
+    /// Owned list of values, which the result will be offseted with,
+    /// in round-robin fashion, fractions are allowed;
+    /// see [`SpinMode::offsets()`](SpinMode::offsets) 
+    /// and [`SpinMode::offsets_f32()`](SpinMode::offsets_f32)
+    OffsetVec(Vec<f32>),
+}
+
+impl SpinMode<'static> {
+
+    /// Create owned offset list from any collection of integers,
+    /// e.g. array, `Vec` or iterator
+    pub fn offsets<I>(values: I) -> Self
+    where I: IntoIterator<Item = i32> {
+        SpinMode::OffsetVec(values.into_iter().map(|value| value as f32).collect())
+    }
+
+    /// Create owned offset list from any collection of floats
+    pub fn offsets_f32<I>(values: I) -> Self
+    where I: IntoIterator<Item = f32> {
+        SpinMode::OffsetVec(values.into_iter().collect())
+    }
+
 }
 
 /// Shortcuts for some common transformations
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum SpinMacro {
-    /// Set absolute HSL goal value
+    /// Set absolute HSL goal value,
+    /// converted to the channels of the spin space
     GradientColor(HslColor),
-    /// Set gray goal value, defined by lightness
+    /// Set multi-stop gradient through the listed colors,
+    /// stops are evenly distributed
+    GradientColors(Vec<HslColor>),
+    /// Set multi-stop gradient through the listed colors,
+    /// at the given positions (0..=1, base color is at 0)
+    GradientColorsAt(Vec<(HslColor, f32)>),
+    /// Set gray goal value, defined by HSL lightness,
+    /// converted to the channels of the spin space
     FadeToGray(i32),
     /// Set goal value to black
     FadeToBlack,
     /// Set goal value to white
     FadeToWhite,
+    /// Hue offsets of complementary harmony: base and its opposite;
+    /// harmony offsets rotate the hue of the spin space, 
+    /// in OKLCH space it's the perceptual hue
+    Complementary,
+    /// Hue offsets of split-complementary harmony: base and 
+    /// the two neighbours of its opposite, at the given angle
+    SplitComplementary(i32),
+    /// Hue offsets of triadic harmony: three evenly spaced hues
+    Triadic,
+    /// Hue offsets of tetradic (square) harmony: four evenly spaced hues
+    Tetradic,
+    /// Hue offsets of rectangle harmony: two complementary pairs,
+    /// the second pair at the given angle
+    Rectangle(i32),
+    /// Hue offsets of analogous harmony: base and 
+    /// its two neighbours, at the given spread
+    Analogous(i32),
 }
 
 /**
 The iterator emits [`HslColor`](HslColor) objects:
 ```
-let mut kw = KolorWheel::new( ... );
-(...)
+# use kolorwheel::{ KolorWheel, HslColor, RgbColor, SpinMode };
+let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 5);
+kw.with_hue(SpinMode::RelativeExcl(360));
 for hsl_color in kw {
   let rgb_color: RgbColor = hsl_color.into();
-  (...)
+  println!("{}", rgb_color);
 }
 ``` 
 */
-
 impl Iterator for KolorWheel {
     ///The iterator emits [`HslColor`](HslColor) objects
-
-#[doc(hidden)]
+    #[doc(hidden)]
     type Item = HslColor;
 
     fn next(&mut self) -> Option<HslColor> {
-        self.spin_iter()
+        self.spin_next_hsla().map(HslColor::from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining();
+        (len, Some(len))
+    }
+
+    /// Jumps to the item directly, without spinning the skipped ones
+    fn nth(&mut self, n: usize) -> Option<HslColor> {
+        self.spin_nth_hsla(n).map(HslColor::from)
+    }
+}
+
+/// The length is the product of the counts of all levels,
+/// see [`total_count()`](KolorWheel::total_count)
+impl ExactSizeIterator for KolorWheel {}
+
+/// Reverse iteration computes each item directly
+impl DoubleEndedIterator for KolorWheel {
+    fn next_back(&mut self) -> Option<HslColor> {
+        self.spin_next_back_hsla().map(HslColor::from)
+    }
+}
+
+/// Iterator, which emits [`HslaColor`](HslaColor) objects,
+/// see [`KolorWheel::hsla_iter()`](KolorWheel::hsla_iter)
+pub struct HslaIter<'k> {
+    kolor_wheel: &'k mut KolorWheel,
+}
+
+impl Iterator for HslaIter<'_> {
+    type Item = HslaColor;
+
+    fn next(&mut self) -> Option<HslaColor> {
+        self.kolor_wheel.spin_next_hsla()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.kolor_wheel.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<HslaColor> {
+        self.kolor_wheel.spin_nth_hsla(n)
+    }
+}
+
+impl ExactSizeIterator for HslaIter<'_> {}
+
+impl DoubleEndedIterator for HslaIter<'_> {
+    fn next_back(&mut self) -> Option<HslaColor> {
+        self.kolor_wheel.spin_next_back_hsla()
     }
 }
 
@@ -100,39 +317,181 @@ impl KolorWheel {
 
     /// Create the object with specified color and 
     /// number of spin steps.
+    /// Zero count is accepted, but emits no colors,
+    /// see [`try_new()`](KolorWheel::try_new) to report it.
+    /// The alpha of an [`HslaColor`](HslaColor) is the base of the alpha spin,
+    /// other colors are opaque
     pub fn new<T>(color: T, count: usize) -> Self 
-    where T: Into<HslColor> {
+    where T: Into<HslaColor> {
 
-        let mut vec = Vec::new();
+        let color: HslaColor = color.into();
         let spinner = Spinner::new(color, count);
-        vec.push(spinner);
-        Self {
+        let mut kolor_wheel = Self {
             index: 0,
-            spinner_vec: vec,
+            selected: None,
+            level_vec: vec![Level::new(spinner)],
+            offset_vec: Vec::new(),
+            path: vec![0],
             level: 0,
             top_level: 0,
+            front: 0,
+            back: 0,
+            min_contrast: None,
+            contrast_fallback: ContrastFallback::default(),
+            deficiency: None,
+        };
+        kolor_wheel.update_layout();
+        kolor_wheel
+    }
+
+    /// Create the object with validation: 
+    /// the count must not be zero, the color channels must be finite
+    pub fn try_new<T>(color: T, count: usize) -> Result<Self, ValidationError>
+    where T: Into<HslaColor> {
+
+        let color: HslaColor = color.into();
+        validate_color(color)?;
+        validate_count(count)?;
+
+        Ok(Self::new(color, count))
+    }
+
+    /// Spinners being configured: the one selected 
+    /// by `fork_with()`, or all the spinners of the level
+    fn current_spinners(&mut self) -> &mut [Spinner] {
+        let spinner_vec = &mut self.level_vec[self.index].spinner_vec;
+        match self.selected {
+            Some(parent) => &mut spinner_vec[parent..=parent],
+            None => spinner_vec,
         }
     }
 
-    fn current_spinner(&mut self) -> &mut Spinner {
-        &mut self.spinner_vec[self.index]
+    /// Set the color space the channels are spun in,
+    /// should be called before setting spin modes
+    pub fn with_space(&mut self, spin_space: SpinSpace) -> &mut Self {
+        for spinner in self.current_spinners() {
+            spinner.with_space(spin_space);
+        }
+        self
     }
 
     /// Set spin mode for Hue channel
     pub fn with_hue(&mut self, spin_mode: SpinMode) -> &mut Self {
-        self.current_spinner().with_hue(spin_mode);
+        for spinner in self.current_spinners() {
+            spinner.with_hue(spin_mode.clone());
+        }
         self
     }
 
     /// Set spin mode for Saturarion channel
     pub fn with_saturation(&mut self, spin_mode: SpinMode) -> &mut Self {
-        self.current_spinner().with_saturation(spin_mode);
+        for spinner in self.current_spinners() {
+            spinner.with_saturation(spin_mode.clone());
+        }
         self
     }
 
     /// Set spin mode for Lightness channel
     pub fn with_lightness(&mut self, spin_mode: SpinMode) -> &mut Self {
-        self.current_spinner().with_lightness(spin_mode);
+        for spinner in self.current_spinners() {
+            spinner.with_lightness(spin_mode.clone());
+        }
+        self
+    }
+
+    /// Set spin mode for Alpha channel, 
+    /// the base is the alpha of the color passed to 
+    /// [`new()`](KolorWheel::new), opaque (100%) by default
+    pub fn with_alpha(&mut self, spin_mode: SpinMode) -> &mut Self {
+        for spinner in self.current_spinners() {
+            spinner.with_alpha(spin_mode.clone());
+        }
+        self
+    }
+
+    /// Set spin mode for Hue channel with validation:
+    /// offset list must not be empty, the offsets must be finite
+    pub fn try_with_hue(&mut self, spin_mode: SpinMode) -> Result<&mut Self, ValidationError> {
+        validate_spin_mode(&spin_mode, false)?;
+        Ok(self.with_hue(spin_mode))
+    }
+
+    /// Set spin mode for Saturation channel with validation:
+    /// absolute target must be in 0..=100 range,
+    /// offset list must not be empty, the offsets must be finite
+    pub fn try_with_saturation(&mut self, spin_mode: SpinMode) -> Result<&mut Self, ValidationError> {
+        validate_spin_mode(&spin_mode, true)?;
+        Ok(self.with_saturation(spin_mode))
+    }
+
+    /// Set spin mode for Lightness channel with validation,
+    /// see [`try_with_saturation()`](KolorWheel::try_with_saturation)
+    pub fn try_with_lightness(&mut self, spin_mode: SpinMode) -> Result<&mut Self, ValidationError> {
+        validate_spin_mode(&spin_mode, true)?;
+        Ok(self.with_lightness(spin_mode))
+    }
+
+    /// Set spin mode for Alpha channel with validation,
+    /// see [`try_with_saturation()`](KolorWheel::try_with_saturation)
+    pub fn try_with_alpha(&mut self, spin_mode: SpinMode) -> Result<&mut Self, ValidationError> {
+        validate_spin_mode(&spin_mode, true)?;
+        Ok(self.with_alpha(spin_mode))
+    }
+
+    /// Set easing curve for the calculated spin of Hue channel
+    pub fn with_hue_easing(&mut self, easing: Easing) -> &mut Self {
+        for spinner in self.current_spinners() {
+            spinner.with_hue_easing(easing);
+        }
+        self
+    }
+
+    /// Set easing curve for the calculated spin of Saturation channel
+    pub fn with_saturation_easing(&mut self, easing: Easing) -> &mut Self {
+        for spinner in self.current_spinners() {
+            spinner.with_saturation_easing(easing);
+        }
+        self
+    }
+
+    /// Set easing curve for the calculated spin of Lightness channel
+    pub fn with_lightness_easing(&mut self, easing: Easing) -> &mut Self {
+        for spinner in self.current_spinners() {
+            spinner.with_lightness_easing(easing);
+        }
+        self
+    }
+
+    /// Set easing curve for the calculated spin of Alpha channel
+    pub fn with_alpha_easing(&mut self, easing: Easing) -> &mut Self {
+        for spinner in self.current_spinners() {
+            spinner.with_alpha_easing(easing);
+        }
+        self
+    }
+
+    /// Set the direction of the hue spin towards absolute targets,
+    /// used by `SpinMode::Absolute` and the gradient macros
+    pub fn with_hue_interpolation(&mut self, interpolation: HueInterpolation) -> &mut Self {
+        for spinner in self.current_spinners() {
+            spinner.with_hue_interpolation(interpolation);
+        }
+        self
+    }
+
+    /// Set the behaviour of Saturation channel beyond 0..=100 range
+    pub fn with_saturation_boundary(&mut self, boundary: Boundary) -> &mut Self {
+        for spinner in self.current_spinners() {
+            spinner.with_saturation_boundary(boundary);
+        }
+        self
+    }
+
+    /// Set the behaviour of Lightness channel beyond 0..=100 range
+    pub fn with_lightness_boundary(&mut self, boundary: Boundary) -> &mut Self {
+        for spinner in self.current_spinners() {
+            spinner.with_lightness_boundary(boundary);
+        }
         self
     }
 
@@ -141,56 +500,426 @@ impl KolorWheel {
 
         match spin_macro {
             SpinMacro::GradientColor(hsl_color) => {
-                self.with_hue(SpinMode::Absolute(hsl_color.h as i32));
-                self.with_saturation(SpinMode::Absolute(hsl_color.s as i32));
-                self.with_lightness(SpinMode::Absolute(hsl_color.l as i32));
+                for spinner in self.current_spinners() {
+                    let channels = spinner.space().hsl_to_channels(hsl_color);
+                    spinner.with_targets(Some(channels.h), Some(channels.s), Some(channels.l));
+                }
+            },
+            SpinMacro::GradientColors(colors) => {
+                let count = colors.len() as f32;
+                let stops: Vec<(HslColor, f32)> = colors.into_iter()
+                    .enumerate()
+                    .map(|(index, color)| (color, (index + 1) as f32 / count))
+                    .collect()
+                ;
+                for spinner in self.current_spinners() {
+                    spinner.with_gradient(&stops);
+                }
+            },
+            SpinMacro::GradientColorsAt(stops) => {
+                for spinner in self.current_spinners() {
+                    spinner.with_gradient(&stops);
+                }
             },
             SpinMacro::FadeToGray(percent) => {
-                self.with_saturation(SpinMode::Absolute(0));
-                self.with_lightness(SpinMode::Absolute(percent));
+                self.fade_to_gray(percent as f32);
             },
             SpinMacro::FadeToBlack => {
-                self.with_saturation(SpinMode::Absolute(0));
-                self.with_lightness(SpinMode::Absolute(0));
+                self.fade_to_gray(0.0);
             },
             SpinMacro::FadeToWhite => {
-                self.with_saturation(SpinMode::Absolute(0));
-                self.with_lightness(SpinMode::Absolute(100));
+                self.fade_to_gray(100.0);
+            },
+            SpinMacro::Complementary => {
+                self.with_hue(SpinMode::Offset(&[0, 180]));
+            },
+            SpinMacro::SplitComplementary(angle) => {
+                self.with_hue(SpinMode::Offset(&[0, 180 - angle, 180 + angle]));
+            },
+            SpinMacro::Triadic => {
+                self.with_hue(SpinMode::Offset(&[0, 120, 240]));
+            },
+            SpinMacro::Tetradic => {
+                self.with_hue(SpinMode::Offset(&[0, 90, 180, 270]));
+            },
+            SpinMacro::Rectangle(angle) => {
+                self.with_hue(SpinMode::Offset(&[0, angle, 180, 180 + angle]));
+            },
+            SpinMacro::Analogous(spread) => {
+                self.with_hue(SpinMode::Offset(&[0, -spread, spread]));
             },
         };
 
         self
     }    
 
+    /// The gray is converted to the spin space,
+    /// e.g. HSL lightness of 40% is OKLCH lightness of ~47%
+    fn fade_to_gray(&mut self, lightness: f32) {
+
+        let gray = HslColor { h: 0.0, s: 0.0, l: lightness };
+
+        for spinner in self.current_spinners() {
+            let channels = spinner.space().hsl_to_channels(gray);
+            spinner.with_targets(None, Some(channels.s), Some(channels.l));
+        }
+    }
+
+    /// Force every emitted color to reach the minimum WCAG 2.x contrast
+    /// ratio against the background, by adjusting its lightness;
+    /// colors which can not reach it are emitted unchanged by default,
+    /// see [`with_contrast_fallback()`](KolorWheel::with_contrast_fallback),
+    /// and reported by [`PaletteEntry::contrast_met`](PaletteEntry::contrast_met)
+    pub fn with_min_contrast<T>(&mut self, background: T, min_ratio: f32) -> &mut Self 
+    where T: Into<HslColor> {
+        self.min_contrast = Some((background.into(), min_ratio));
+        self
+    }
+
+    /// Set what to emit for colors, which can not reach
+    /// the ratio of [`with_min_contrast()`](KolorWheel::with_min_contrast)
+    pub fn with_contrast_fallback(&mut self, fallback: ContrastFallback) -> &mut Self {
+        self.contrast_fallback = fallback;
+        self
+    }
+
+    /// The color with the minimum contrast applied, and whether
+    /// the ratio is met, `None` if no minimum contrast is set
+    fn apply_min_contrast(&self, color: HslColor) -> (HslColor, Option<bool>) {
+
+        let Some((background, min_ratio)) = self.min_contrast else {
+            return (color, None);
+        };
+
+        if let Some(adjusted) = color.with_min_contrast(background, min_ratio) {
+            return (adjusted, Some(true));
+        }
+
+        let fallback = match self.contrast_fallback {
+            ContrastFallback::Keep => color,
+            ContrastFallback::BlackOrWhite => {
+                let black = HslColor { l: 0.0, ..color };
+                let white = HslColor { l: 100.0, ..color };
+                if black.contrast_ratio(background) > white.contrast_ratio(background) {
+                    black
+                } else {
+                    white
+                }
+            },
+        };
+
+        (fallback, Some(false))
+    }
+
+    /// Emit every color as seen with the color vision deficiency,
+    /// applied after all other stages
+    pub fn with_deficiency(&mut self, deficiency: ColorDeficiency) -> &mut Self {
+        self.deficiency = Some(deficiency);
+        self
+    }
+
+    fn apply_deficiency(&self, color: HslColor) -> HslColor {
+
+        match self.deficiency {
+            Some(deficiency) => color.simulate_deficiency(deficiency),
+            None => color,
+        }
+    }
+
     /**
     Fork the  state of KolorWheel,
     produce a separate series of colors
     using current item as base color,
     the size of sub-series should be specified:
     ```
-    let mut kw = KolorWheel::new( ... );
-    (...)
+    # use kolorwheel::{ KolorWheel, HslColor, SpinMode };
+    let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 3);
+    kw.with_lightness(SpinMode::RelativeIncl(30));
     kw.fork(5);
     kw.with_hue(SpinMode::RelativeIncl(45));
     ```
     */
     pub fn fork(&mut self, count: usize) -> &mut Self {
 
-        let color = self.current_spinner().color();
+        let spinner = self.fork_spinner(0, count);
+        self.push_level(Level::new(spinner));
 
-        let spinner = Spinner::new(color, count);
-        self.spinner_vec.push(spinner);
+        self
+    }
+
+    /**
+    Fork with separate settings for each position of the parent,
+    the settings are returned by the closure for the parent index:
+    ```
+    # use kolorwheel::{ KolorWheel, HslColor, LevelRecipe, SpinMacro };
+    let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 2);
+    kw.fork_with(|parent| {
+        let count = if parent == 0 { 3 } else { 5 };
+        LevelRecipe { count, macros: vec![SpinMacro::FadeToWhite], ..LevelRecipe::default() }
+    });
+    ```
+    */
+    pub fn fork_with<F>(&mut self, recipe: F) -> &mut Self
+    where F: FnMut(usize) -> LevelRecipe {
+
+        let parent_count = self.level_vec[self.top_level].max_count();
+        let recipes: Vec<LevelRecipe> = (0..parent_count).map(recipe).collect();
+
+        self.fork_each(&recipes)
+    }
+
+    /// Fork with a table of settings, one for each position of the parent,
+    /// used in round-robin manner if the table is shorter;
+    /// further `with_...()` calls affect all of them
+    pub fn fork_each(&mut self, recipes: &[LevelRecipe]) -> &mut Self {
+
+        if recipes.is_empty() {
+            return self.fork(0);
+        }
+
+        let spinner_vec = recipes.iter()
+            .enumerate()
+            .map(|(parent, recipe)| self.fork_spinner(parent, recipe.count))
+            .collect()
+        ;
+        self.push_level(Level { spinner_vec });
+
+        for (parent, recipe) in recipes.iter().enumerate() {
+            self.selected = Some(parent);
+            recipe.apply(self);
+        }
+        self.selected = None;
+
+        self
+    }
+
+    /// Spinner of the fork for the position of the parent level,
+    /// inheriting from the parent spinner picked the same way as on spin
+    fn fork_spinner(&self, parent: usize, count: usize) -> Spinner {
+
+        let parent = self.level_vec[self.top_level].spinner(parent);
+
+        let mut spinner = Spinner::new(parent.color(), count);
+        spinner.with_space(parent.space());
+
+        spinner
+    }
+
+    fn push_level(&mut self, level: Level) {
+
+        self.level_vec.push(level);
+        self.path.push(0);
         self.top_level += 1;
         self.index += 1;
+        self.selected = None;
 
-        self
+        self.update_layout();
+    }
+
+    /// Number of colors below the positions of each level, 
+    /// as running sums, used for random access
+    fn update_layout(&mut self) {
+
+        let mut offset_vec: Vec<Vec<usize>> = Vec::with_capacity(self.level_vec.len());
+
+        for (level_index, level) in self.level_vec.iter().enumerate().rev() {
+            let mut offsets = Vec::with_capacity(level.max_count() + 1);
+            let mut sum = 0;
+            offsets.push(sum);
+            for parent in 0..level.max_count() {
+                sum += match offset_vec.last() {
+                    None => 1,
+                    Some(child_offsets) => {
+                        let child = self.level_vec[level_index + 1].spinner(parent);
+                        child_offsets[child.count()]
+                    },
+                };
+                offsets.push(sum);
+            }
+            offset_vec.push(offsets);
+        }
+
+        offset_vec.reverse();
+        self.offset_vec = offset_vec;
+    }
+
+    /// Fork with validation: the count must not be zero
+    pub fn try_fork(&mut self, count: usize) -> Result<&mut Self, ValidationError> {
+        validate_count(count)?;
+        Ok(self.fork(count))
+    }
+
+    /// Iterate colors with alpha channel, instead of [`HslColor`](HslColor):
+    /// ```
+    /// # use kolorwheel::{ KolorWheel, HslColor, RgbaColor, SpinMode };
+    /// # let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 4);
+    /// # kw.with_alpha(SpinMode::Absolute(25));
+    /// for hsla_color in kw.hsla_iter() {
+    ///     let rgba_color: RgbaColor = hsla_color.into();
+    ///     println!("{:?}", rgba_color);
+    /// }
+    /// ```
+    pub fn hsla_iter(&mut self) -> HslaIter<'_> {
+        HslaIter { kolor_wheel: self }
+    }
+
+    /// Total number of colors, the product of the counts of all levels
+    /// (or the sum of the branches with per-parent settings),
+    /// regardless of the iteration state
+    pub fn total_count(&self) -> usize {
+        let count = self.level_vec[0].spinner(0).count();
+        self.offset_vec[0][count]
+    }
+
+    /// Get the color at the index directly, 
+    /// in O(depth * log count) time, without iterating;
+    /// regardless of the iteration state
+    pub fn get(&self, index: usize) -> Option<HslColor> {
+        self.get_hsla(index).map(HslColor::from)
+    }
+
+    /// Get the color with alpha channel at the index directly,
+    /// see [`get()`](KolorWheel::get)
+    pub fn get_hsla(&self, index: usize) -> Option<HslaColor> {
+        let color = self.spin_at(index)?;
+        Some(self.apply_stages(color))
+    }
+
+    fn spin_at(&self, index: usize) -> Option<HslaColor> {
+        let (_, color) = self.spin_levels_at(index)?.pop()?;
+        Some(color)
+    }
+
+    /// Position and color of each level for the index, 
+    /// from the base level to the top level
+    fn spin_levels_at(&self, index: usize) -> Option<Vec<(usize, HslaColor)>> {
+
+        let counters = self.counters_at(index)?;
+
+        let mut levels: Vec<(usize, HslaColor)> = Vec::with_capacity(counters.len());
+        let mut parent = 0;
+        for (level, counter) in self.level_vec.iter().zip(counters) {
+            let spinner = level.spinner(parent);
+            let color = match levels.last() {
+                None => spinner.spin_at(counter),
+                Some(&(_, base)) => spinner.spin_at_with_base(base, counter),
+            };
+            levels.push((counter, color));
+            parent = counter;
+        }
+
+        Some(levels)
+    }
+
+    /// Position of each level for the index
+    fn counters_at(&self, index: usize) -> Option<Vec<usize>> {
+
+        if index >= self.total_count() {
+            return None;
+        }
+
+        let mut counters = Vec::with_capacity(self.level_vec.len());
+        let mut rest = index;
+        let mut parent = 0;
+        for (level, offsets) in self.level_vec.iter().zip(&self.offset_vec) {
+            let count = level.spinner(parent).count();
+            let counter = offsets[..=count].partition_point(|&offset| offset <= rest) - 1;
+            rest -= offsets[counter];
+            counters.push(counter);
+            parent = counter;
+        }
+
+        Some(counters)
+    }
+
+    /// Position the spinners, so the next spin emits the item at the index
+    fn seek(&mut self, index: usize) {
+
+        let Some(counters) = self.counters_at(index) else {
+            return;
+        };
+
+        let mut color = None;
+        let mut parent = 0;
+
+        for (level, counter) in counters.into_iter().enumerate() {
+            let spinner = self.level_vec[level].spinner_mut(parent);
+            if let Some(color) = color {
+                spinner.with_color(color);
+            }
+            spinner.seek(counter);
+            if level < self.top_level {
+                color = Some(spinner.spin_next());
+            }
+            self.path[level] = counter;
+            parent = counter;
+        }
+
+        self.level = self.top_level;
+    }
+
+    fn remaining(&self) -> usize {
+        self.total_count().saturating_sub(self.front + self.back)
+    }
+
+    fn apply_stages(&self, color: HslaColor) -> HslaColor {
+        let (color, _) = self.apply_stages_reporting(color);
+        color
+    }
+
+    /// Apply the stages, also report whether the minimum contrast is met
+    fn apply_stages_reporting(&self, color: HslaColor) -> (HslaColor, Option<bool>) {
+        let (hsl, contrast_met) = self.apply_min_contrast(HslColor::from(color));
+        let hsl = self.apply_deficiency(hsl);
+        (HslaColor::from_hsl(hsl, color.a), contrast_met)
+    }
+
+    fn spin_next_hsla(&mut self) -> Option<HslaColor> {
+
+        if self.remaining() == 0 {
+            return None;
+        }
+
+        let color = self.spin_iter()?;
+        self.front += 1;
+        Some(self.apply_stages(color))
+    }
+
+    fn spin_nth_hsla(&mut self, n: usize) -> Option<HslaColor> {
+
+        if n >= self.remaining() {
+            self.front = self.total_count() - self.back;
+            return None;
+        }
+
+        if n > 0 {
+            self.front += n;
+            self.seek(self.front);
+        }
+
+        self.spin_next_hsla()
+    }
+
+    fn spin_next_back_hsla(&mut self) -> Option<HslaColor> {
+
+        if self.remaining() == 0 {
+            return None;
+        }
+
+        self.back += 1;
+        let index = self.total_count() - self.back;
+        self.get_hsla(index)
     }
 
-    fn spin_iter(&mut self) -> Option<HslColor> {
+    fn spin_iter(&mut self) -> Option<HslaColor> {
 
         loop {
 
-            let spinner = &mut self.spinner_vec[self.level];
+            let parent = match self.level {
+                0 => 0,
+                level => self.path[level - 1],
+            };
+            let spinner = self.level_vec[self.level].spinner_mut(parent);
 
             if spinner.spin_finished() {
                 if self.level == 0 { 
@@ -200,14 +929,16 @@ impl KolorWheel {
                 continue;
             }
                 
+            let counter = spinner.counter();
             let color = spinner.spin_next();
+            self.path[self.level] = counter;
 
             if self.level == self.top_level {  // render only top level
                 return Some(color);
             }
 
             self.level += 1;
-            let child = &mut self.spinner_vec[self.level];    
+            let child = self.level_vec[self.level].spinner_mut(counter);
             child.rewind().with_color(color);            
 
         }
@@ -218,6 +949,7 @@ impl KolorWheel {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use all_asserts::*;
     use assert_float_eq::*;
 
     #[test]
@@ -326,6 +1058,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::explicit_counter_loop)]
     fn spin_iter_fade_to_gray() {
 
         let base = HslColor::new(90, 80, 10);
@@ -351,4 +1084,651 @@ mod tests {
 
     }
 
+    #[test]
+    fn spin_vec_easing_in_fork() {
+        let base = HslColor::new(0, 100, 50);
+        let result: Vec<HslColor> = KolorWheel::new(base, 2)
+            .with_hue(SpinMode::Absolute(120))
+            .fork(5)
+            .with_lightness(SpinMode::Absolute(90))
+            .with_lightness_easing(Easing::EaseOut)
+            .collect()
+        ;
+        assert_eq!(result.len(), 10);
+        for row in 0..2 {
+            assert_f32_near!(result[row * 5].l, 50.0, 99999);
+            assert_gt!(result[row * 5 + 1].l, 60.0);
+            assert_f32_near!(result[row * 5 + 4].l, 90.0, 99999);
+        }
+    }
+
+    #[test]
+    fn spin_vec_gradient_colors() {
+        let blue = HslColor::new(240, 100, 50);
+        let teal = HslColor::new(180, 100, 30);
+        let yellow = HslColor::new(60, 100, 50);
+        let red = HslColor::new(0, 100, 50);
+        let result: Vec<HslColor> = KolorWheel::new(blue, 7)
+            .with_macro(SpinMacro::GradientColors(vec![teal, yellow, red]))
+            .collect()
+        ;
+        assert_eq!(result.len(), 7);
+        assert_eq!(result[0], blue);
+        assert_eq!(result[2], teal);
+        assert_eq!(result[4], yellow);
+        assert_eq!(result[6], red);
+        assert_f32_near!(result[1].h, 210.0, 99999);
+        assert_f32_near!(result[1].l, 40.0, 99999);
+    }
+
+    #[test]
+    fn spin_vec_gradient_colors_in_fork() {
+        let base = HslColor::new(0, 100, 50);
+        let result: Vec<HslColor> = KolorWheel::new(base, 2)
+            .with_hue(SpinMode::Absolute(180))
+            .fork(3)
+            .with_macro(SpinMacro::GradientColorsAt(vec![
+                (HslColor::new(90, 100, 80), 0.5),
+                (HslColor::new(90, 0, 100), 1.0),
+            ]))
+            .collect()
+        ;
+        assert_eq!(result.len(), 6);
+        assert_f32_near!(result[0].h, 0.0, 99999);
+        assert_f32_near!(result[3].h, 180.0, 99999);
+        assert_f32_near!(result[1].l, 80.0, 99999);
+        assert_f32_near!(result[4].l, 80.0, 99999);
+        assert_f32_near!(result[5].l, 100.0, 99999);
+    }
+
+    #[test]
+    fn spin_vec_triadic_with_shades() {
+        let base = HslColor::new(30, 80, 40);
+        let result: Vec<HslColor> = KolorWheel::new(base, 3)
+            .with_macro(SpinMacro::Triadic)
+            .fork(4)
+            .with_lightness(SpinMode::Absolute(85))
+            .collect()
+        ;
+        assert_eq!(result.len(), 12);
+        assert_f32_near!(result[0].h, 30.0, 99999);
+        assert_f32_near!(result[4].h, 150.0, 99999);
+        assert_f32_near!(result[8].h, 270.0, 99999);
+        assert_f32_near!(result[0].l, 40.0, 99999);
+        assert_f32_near!(result[11].l, 85.0, 99999);
+    }
+
+    #[test]
+    fn spin_vec_harmony_hues() {
+        let base = HslColor::new(0, 100, 50);
+        let hues = |spin_macro: SpinMacro, count: usize| -> Vec<f32> {
+            KolorWheel::new(base, count)
+                .with_macro(spin_macro)
+                .map(|color| color.h)
+                .collect()
+        };
+        assert_eq!(hues(SpinMacro::Complementary, 2), vec![0.0, 180.0]);
+        assert_eq!(hues(SpinMacro::SplitComplementary(30), 3), vec![0.0, 150.0, 210.0]);
+        assert_eq!(hues(SpinMacro::Tetradic, 4), vec![0.0, 90.0, 180.0, 270.0]);
+        assert_eq!(hues(SpinMacro::Rectangle(60), 4), vec![0.0, 60.0, 180.0, 240.0]);
+        assert_eq!(hues(SpinMacro::Analogous(30), 3), vec![0.0, 330.0, 30.0]);
+    }
+
+    #[test]
+    fn spin_vec_min_contrast() {
+        let background = HslColor::new(0, 0, 100);
+        let base = HslColor::new(0, 100, 50);
+        let result: Vec<HslColor> = KolorWheel::new(base, 6)
+            .with_hue(SpinMode::RelativeExcl(360))
+            .with_min_contrast(background, 4.5)
+            .collect()
+        ;
+        assert_eq!(result.len(), 6);
+        for color in result {
+            assert_ge!(RgbColor::from(color).contrast_ratio(RgbColor::from(background)), 4.5);
+        }
+    }
+
+    #[test]
+    fn spin_vec_min_contrast_impossible() {
+        let background = HslColor::new(0, 0, 50);
+        let base = HslColor::new(0, 100, 50);
+        let result: Vec<HslColor> = KolorWheel::new(base, 1)
+            .with_min_contrast(background, 7.0)
+            .collect()
+        ;
+        assert_eq!(result, vec![base]);
+    }
+
+    #[test]
+    fn spin_vec_min_contrast_fallback() {
+        let background = HslColor::new(0, 0, 50);
+        let base = HslColor::new(0, 100, 50);
+        let result: Vec<HslColor> = KolorWheel::new(base, 1)
+            .with_min_contrast(background, 7.0)
+            .with_contrast_fallback(ContrastFallback::BlackOrWhite)
+            .collect()
+        ;
+        assert_f32_near!(result[0].l, 0.0, 99999);
+    }
+
+    #[test]
+    fn spin_vec_deficiency() {
+        let base = HslColor::new(0, 100, 50);
+        let original: Vec<HslColor> = KolorWheel::new(base, 4)
+            .with_hue(SpinMode::RelativeExcl(360))
+            .collect()
+        ;
+        let simulated: Vec<HslColor> = KolorWheel::new(base, 4)
+            .with_hue(SpinMode::RelativeExcl(360))
+            .with_deficiency(ColorDeficiency::Protanopia)
+            .collect()
+        ;
+        assert_eq!(simulated.len(), 4);
+        for (original, simulated) in original.iter().zip(simulated.iter()) {
+            assert_eq!(*simulated, original.simulate_deficiency(ColorDeficiency::Protanopia));
+        }
+    }
+
+    fn random_access_wheel() -> KolorWheel {
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 3);
+        kw.with_hue(SpinMode::RelativeExcl(360));
+        kw.with_alpha(SpinMode::Offset(&[0, -20]));
+        kw.fork(4);
+        kw.with_lightness(SpinMode::Absolute(90));
+        kw.with_space(SpinSpace::Oklch);
+        kw.fork(2);
+        kw.with_saturation(SpinMode::Offset(&[0, -30]));
+        kw
+    }
+
+    #[test]
+    fn spin_exact_size() {
+        let mut kw = random_access_wheel();
+        assert_eq!(kw.len(), 24);
+        kw.next();
+        kw.next_back();
+        assert_eq!(kw.len(), 22);
+        assert_eq!(kw.total_count(), 24);
+        assert_eq!(kw.count(), 22);
+    }
+
+    #[test]
+    fn spin_get_same_as_iteration() {
+        let expected: Vec<HslaColor> = random_access_wheel().hsla_iter().collect();
+        let kw = random_access_wheel();
+        for (index, color) in expected.iter().enumerate() {
+            assert_eq!(kw.get_hsla(index), Some(*color));
+        }
+        assert_eq!(kw.get(24), None);
+    }
+
+    #[test]
+    fn spin_reverse_iteration() {
+        let mut expected: Vec<HslColor> = random_access_wheel().collect();
+        expected.reverse();
+        let result: Vec<HslColor> = random_access_wheel().rev().collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn spin_nth_and_both_ends() {
+        let expected: Vec<HslColor> = random_access_wheel().collect();
+
+        let mut kw = random_access_wheel();
+        assert_eq!(kw.nth(5), Some(expected[5]));
+        assert_eq!(kw.next(), Some(expected[6]));
+        assert_eq!(kw.nth(10), Some(expected[17]));
+        assert_eq!(kw.next_back(), Some(expected[23]));
+        let rest: Vec<HslColor> = kw.collect();
+        assert_eq!(rest, expected[18..23].to_vec());
+
+        let mut kw = random_access_wheel();
+        assert_eq!(kw.nth(30), None);
+        assert_eq!(kw.next(), None);
+        assert_eq!(kw.next_back(), None);
+    }
+
+    #[test]
+    fn spin_gradient_color_shorter_hue() {
+        let mut kw = KolorWheel::new(HslColor::new(350, 100, 50), 5);
+        kw.with_hue_interpolation(HueInterpolation::Shorter);
+        kw.with_macro(SpinMacro::GradientColor(HslColor::new(10, 100, 50)));
+
+        let hues: Vec<f32> = kw.map(|color| color.h).collect();
+        let expected = [350.0, 355.0, 0.0, 5.0, 10.0];
+        for (hue, expected) in hues.iter().zip(expected) {
+            assert_float_absolute_eq!(*hue, expected, 0.001);
+        }
+    }
+
+    #[test]
+    fn spin_lightness_boundary_no_stall() {
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 70), 5);
+        kw.with_lightness(SpinMode::RelativeIncl(60));
+        kw.with_lightness_boundary(Boundary::Reflect);
+
+        let colors: Vec<HslColor> = kw.collect();
+        let lightness: Vec<f32> = colors.iter().map(|color| color.l).collect();
+        assert_eq!(lightness, vec![70.0, 85.0, 100.0, 85.0, 70.0]);
+    }
+
+    #[test]
+    fn spin_rescale_boundary_random_access() {
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 20), 4);
+        kw.with_lightness(SpinMode::RelativeIncl(60));
+        kw.fork(5);
+        kw.with_lightness(SpinMode::RelativeIncl(50));
+        kw.with_lightness(SpinMode::Offset(&[0, 10]));
+        kw.with_lightness_boundary(Boundary::Rescale);
+
+        let expected: Vec<HslColor> = kw.by_ref().collect();
+        for (index, color) in expected.iter().enumerate() {
+            assert_eq!(kw.get(index), Some(*color));
+            assert_le!(color.l, 100.0);
+        }
+        assert_f32_near!(expected[15].l, 80.0, 99999);  // last base, unchanged
+        assert_f32_near!(expected[19].l, 100.0, 99999);
+        assert_f32_near!(expected[18].l, 99.0, 99999);
+    }
+
+    #[test]
+    fn spin_fork_abs_target_per_parent() {
+        let mut kw = KolorWheel::new(HslColor::new(200, 80, 10), 4);
+        kw.with_lightness(SpinMode::RelativeIncl(60));
+        kw.fork(3);
+        kw.with_lightness(SpinMode::Absolute(95));
+
+        let colors: Vec<HslColor> = kw.collect();
+        for row in colors.chunks(3) {
+            assert_f32_near!(row[2].l, 95.0, 99999);
+        }
+        assert_f32_near!(colors[9].l, 70.0, 99999);
+        assert_f32_near!(colors[10].l, 82.5, 99999);
+    }
+
+    fn per_parent_wheel() -> KolorWheel {
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 3);
+        kw.with_hue(SpinMode::RelativeExcl(360));
+        kw.fork_with(|parent| LevelRecipe {
+            count: [3, 5, 0][parent],
+            lightness: vec![SpinRecipe::RelativeIncl(10 * parent as i32 + 10)],
+            ..LevelRecipe::default()
+        });
+        kw.with_saturation(SpinMode::RelativeIncl(-20));
+        kw.fork(2);
+        kw.with_alpha(SpinMode::Offset(&[0, -50]));
+        kw
+    }
+
+    #[test]
+    fn spin_fork_with_counts() {
+        let mut kw = per_parent_wheel();
+        assert_eq!(kw.total_count(), 16);
+        assert_eq!(kw.len(), 16);
+
+        let colors: Vec<HslColor> = kw.by_ref().collect();
+        assert_eq!(colors.len(), 16);
+        assert_f32_near!(colors[4].l, 60.0, 99999);  // 1st parent, last shade
+        assert_f32_near!(colors[14].l, 70.0, 99999);  // 2nd parent, last shade
+        assert_f32_near!(colors[14].s, 80.0, 99999);
+        assert_f32_near!(colors[14].h, 120.0, 99999);
+    }
+
+    #[test]
+    fn spin_fork_with_random_access() {
+        let expected: Vec<HslaColor> = per_parent_wheel().hsla_iter().collect();
+        let kw = per_parent_wheel();
+        for (index, color) in expected.iter().enumerate() {
+            assert_eq!(kw.get_hsla(index), Some(*color));
+        }
+        assert_eq!(kw.get(16), None);
+        assert_eq!(kw.get_entry(7).unwrap().path, vec![1, 0, 1]);
+
+        let mut reversed: Vec<HslaColor> = per_parent_wheel().hsla_iter().rev().collect();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+
+        let mut kw = per_parent_wheel();
+        assert_eq!(kw.hsla_iter().nth(9), Some(expected[9]));
+        let rest: Vec<HslaColor> = kw.hsla_iter().collect();
+        assert_eq!(rest, expected[10..].to_vec());
+    }
+
+    #[test]
+    fn spin_fork_each_round_robin() {
+        let short = LevelRecipe { count: 2, ..LevelRecipe::default() };
+        let long = LevelRecipe { count: 4, ..LevelRecipe::default() };
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 5);
+        kw.fork_each(&[short, long]);
+        assert_eq!(kw.total_count(), 2 + 4 + 2 + 4 + 2);
+        assert_eq!(kw.count(), 14);
+    }
+
+    #[test]
+    fn spin_fork_each_inherits_parent_space() {
+        let hsl = LevelRecipe { count: 2, ..LevelRecipe::default() };
+        let oklch = LevelRecipe { count: 2, space: Some(SpinSpace::Oklch), ..LevelRecipe::default() };
+        let inherit = LevelRecipe { count: 3, ..LevelRecipe::default() };
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 2);
+        kw.fork_each(&[hsl, oklch]);
+        kw.fork_each(&[inherit.clone(), inherit]);
+        assert_eq!(kw.level_vec[2].spinner(0).space(), SpinSpace::Hsl);
+        assert_eq!(kw.level_vec[2].spinner(1).space(), SpinSpace::Oklch);
+    }
+
+    #[test]
+    fn spin_zero_count_is_empty() {
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 0);
+        kw.with_lightness(SpinMode::Absolute(100));
+        assert_eq!(kw.count(), 0);
+    }
+
+    #[test]
+    fn spin_try_new_errors() {
+        let result = KolorWheel::try_new(HslColor::new(0, 100, 50), 0);
+        assert!(matches!(result, Err(ValidationError::ZeroCount)));
+
+        let color = HslColor { h: f32::NAN, s: 100.0, l: 50.0 };
+        let result = KolorWheel::try_new(color, 5);
+        assert!(matches!(result, Err(ValidationError::NonFinite)));
+
+        let color = HslColor { h: 0.0, s: f32::NAN, l: 50.0 };
+        let result = KolorWheel::try_new(color, 5);
+        assert!(matches!(result, Err(ValidationError::NonFinite)));
+
+        let color = HslColor { h: 0.0, s: 100.0, l: f32::NAN };
+        let result = KolorWheel::try_new(color, 5);
+        assert!(matches!(result, Err(ValidationError::NonFinite)));
+
+        assert!(KolorWheel::try_new(HslColor::new(0, 100, 50), 1).is_ok());
+    }
+
+    #[test]
+    fn spin_try_new_infinite() {
+        let color = HslColor { h: f32::INFINITY, s: 100.0, l: 50.0 };
+        assert!(matches!(KolorWheel::try_new(color, 5), Err(ValidationError::NonFinite)));
+
+        let color = HslColor { h: 0.0, s: 100.0, l: f32::NEG_INFINITY };
+        assert!(matches!(KolorWheel::try_new(color, 5), Err(ValidationError::NonFinite)));
+
+        let color = HslaColor { h: 0.0, s: 100.0, l: 50.0, a: f32::INFINITY };
+        assert!(matches!(KolorWheel::try_new(color, 5), Err(ValidationError::NonFinite)));
+    }
+
+    #[test]
+    fn spin_try_fork_after_rejected_infinity() {
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 3);
+        assert_eq!(kw.try_with_lightness(SpinMode::offsets_f32([f32::INFINITY])).err(), Some(ValidationError::NonFinite));
+        assert!(kw.try_fork(2).is_ok());
+        let result: Vec<HslColor> = kw.collect();
+        assert_eq!(result.len(), 6);
+        assert!(result.iter().all(|color| color.l.is_finite() && color.l == 50.0));
+    }
+
+    #[test]
+    fn spin_try_fork_after_rejected_nan() {
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 3);
+        assert_eq!(kw.try_with_hue(SpinMode::offsets_f32([10.0, f32::NAN])).err(), Some(ValidationError::NonFinite));
+        assert!(kw.try_fork(2).is_ok());
+        kw.with_lightness(SpinMode::RelativeIncl(20));
+        let result: Vec<HslColor> = kw.collect();
+        assert_eq!(result.len(), 6);
+        for color in result {
+            assert!(!color.h.is_nan() && !color.s.is_nan() && !color.l.is_nan());
+            assert_f32_near!(color.h, 0.0, 99999);
+        }
+    }
+
+    #[test]
+    fn spin_try_fork_after_rejected_target() {
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 3);
+        assert_eq!(kw.try_with_lightness(SpinMode::Absolute(120)).err(), Some(ValidationError::TargetOutOfRange(120)));
+        assert_eq!(kw.try_fork(0).err(), Some(ValidationError::ZeroCount));
+        assert!(kw.try_fork(2).is_ok());
+        let result: Vec<HslColor> = kw.collect();
+        assert_eq!(result.len(), 6);
+        for color in result {
+            assert_f32_near!(color.l, 50.0, 99999);
+        }
+    }
+
+    #[test]
+    fn spin_single_step_emits_base() {
+        let base = HslColor::new(0, 100, 50);
+        let result: Vec<HslColor> = KolorWheel::new(base, 1)
+            .with_hue(SpinMode::RelativeIncl(90))
+            .with_lightness(SpinMode::Absolute(100))
+            .collect()
+        ;
+        assert_eq!(result, vec![base]);
+    }
+
+    #[test]
+    fn spin_try_fork_error() {
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 3);
+        assert_eq!(kw.try_fork(0).err(), Some(ValidationError::ZeroCount));
+        assert!(kw.try_fork(2).is_ok());
+        assert_eq!(kw.count(), 6);
+    }
+
+    #[test]
+    fn spin_try_with_errors() {
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 3);
+
+        assert_eq!(kw.try_with_hue(SpinMode::Offset(&[])).err(), Some(ValidationError::EmptyOffsets));
+        assert_eq!(kw.try_with_hue(SpinMode::offsets([])).err(), Some(ValidationError::EmptyOffsets));
+        assert_eq!(kw.try_with_hue(SpinMode::offsets_f32([f32::NAN])).err(), Some(ValidationError::NonFinite));
+        assert_eq!(kw.try_with_saturation(SpinMode::Absolute(101)).err(), Some(ValidationError::TargetOutOfRange(101)));
+        assert_eq!(kw.try_with_lightness(SpinMode::Absolute(-5)).err(), Some(ValidationError::TargetOutOfRange(-5)));
+        assert_eq!(kw.try_with_alpha(SpinMode::Absolute(200)).err(), Some(ValidationError::TargetOutOfRange(200)));
+
+        assert!(kw.try_with_hue(SpinMode::Absolute(400)).is_ok());
+        assert!(kw.try_with_lightness(SpinMode::Absolute(100)).is_ok());
+        let result: Vec<HslColor> = kw.collect();
+        assert_f32_near!(result[2].l, 100.0, 99999);
+    }
+
+    #[test]
+    fn spin_owned_offsets() {
+        let base = HslColor::new(0, 100, 50);
+
+        let mut kw = KolorWheel::new(base, 4);
+        kw.with_hue(SpinMode::Offset(&[0, 30, 60]));
+        let expected: Vec<HslColor> = kw.collect();
+
+        let offsets: Vec<i32> = (0..3).map(|i| i * 30).collect();
+        let mut kw = KolorWheel::new(base, 4);
+        kw.with_hue(SpinMode::offsets(offsets));
+        let result: Vec<HslColor> = kw.collect();
+        assert_eq!(result, expected);
+
+        let mut kw = KolorWheel::new(base, 4);
+        kw.with_hue(SpinMode::offsets([0, 30, 60]));
+        let result: Vec<HslColor> = kw.collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn spin_owned_offsets_f32() {
+        let base = HslColor::new(0, 100, 50);
+        let mut kw = KolorWheel::new(base, 3);
+        kw.with_lightness(SpinMode::offsets_f32([0.0, 2.5, -7.25]));
+        let result: Vec<HslColor> = kw.collect();
+        assert_f32_near!(result[0].l, 50.0, 99999);
+        assert_f32_near!(result[1].l, 52.5, 99999);
+        assert_f32_near!(result[2].l, 42.75, 99999);
+    }
+
+    #[test]
+    fn spin_hsla_alpha_in_fork() {
+        let base = HslColor::new(0, 100, 50);
+        let mut kw = KolorWheel::new(base, 2);
+        kw.with_alpha(SpinMode::Offset(&[0, -40]));
+        kw.fork(3);
+        kw.with_alpha(SpinMode::RelativeIncl(-20));
+        let result: Vec<HslaColor> = kw.hsla_iter().collect();
+        assert_eq!(result.len(), 6);
+        assert_f32_near!(result[0].a, 100.0, 99999);
+        assert_f32_near!(result[2].a, 80.0, 99999);
+        assert_f32_near!(result[3].a, 60.0, 99999);
+        assert_f32_near!(result[5].a, 40.0, 99999);
+    }
+
+    #[test]
+    fn spin_hsla_base_alpha() {
+        let base = HslaColor::new(0, 100, 50, 50);
+        let mut kw = KolorWheel::new(base, 3);
+        kw.with_alpha(SpinMode::RelativeIncl(-30));
+        let result: Vec<HslaColor> = kw.hsla_iter().collect();
+        assert_eq!(result[0], base);
+        assert_f32_near!(result[2].a, 20.0, 99999);
+
+        let opaque: Vec<HslaColor> = KolorWheel::new(HslColor::from(base), 1).hsla_iter().collect();
+        assert_f32_near!(opaque[0].a, 100.0, 99999);
+    }
+
+    #[test]
+    fn spin_hsla_keeps_alpha_with_contrast() {
+        let base = HslColor::new(0, 100, 50);
+        let mut kw = KolorWheel::new(base, 2);
+        kw.with_alpha(SpinMode::Absolute(50));
+        kw.with_min_contrast(HslColor::new(0, 0, 100), 4.5);
+        let result: Vec<HslaColor> = kw.hsla_iter().collect();
+        assert_f32_near!(result[1].a, 50.0, 99999);
+        assert_ge!(RgbColor::from(HslColor::from(result[1])).contrast_ratio(RgbColor { r: 255, g: 255, b: 255 }), 4.5);
+    }
+
+    #[test]
+    fn spin_vec_hsv_base_and_gradient() {
+        let base = HsvColor::new(0, 100, 100);
+        let target = HsvColor::new(240, 100, 100);
+        let result: Vec<HslColor> = KolorWheel::new(base, 3)
+            .with_macro(SpinMacro::GradientColor(target.into()))
+            .collect()
+        ;
+        assert_f32_near!(result[0].h, 0.0, 99999);
+        assert_f32_near!(result[0].l, 50.0, 99999);
+        assert_f32_near!(result[2].h, 240.0, 99999);
+    }
+
+    #[test]
+    fn spin_vec_oklch_gradient() {
+        let base = HslColor::new(240, 100, 50);
+        let target = HslColor::new(60, 100, 50);
+        let result: Vec<HslColor> = KolorWheel::new(base, 5)
+            .with_space(SpinSpace::Oklch)
+            .with_macro(SpinMacro::GradientColor(target))
+            .collect()
+        ;
+        assert_eq!(result.len(), 5);
+        assert_float_absolute_eq!(result[0].h, 240.0, 0.5);
+        assert_float_absolute_eq!(result[4].h, 60.0, 1.0);
+        assert_float_absolute_eq!(result[4].l, 50.0, 1.0);
+    }
+
+    #[test]
+    fn spin_vec_oklch_fork_inherits_space() {
+        let base = HslColor::new(240, 100, 50);
+        let mut kw = KolorWheel::new(base, 2);
+        kw.with_space(SpinSpace::Oklch);
+        kw.fork(3);
+        assert_eq!(kw.current_spinners()[0].space(), SpinSpace::Oklch);
+    }
+
+    fn oklch_macro_last(base: HslColor, spin_macro: SpinMacro) -> HslColor {
+        let mut kw = KolorWheel::new(base, 5);
+        kw.with_space(SpinSpace::Oklch);
+        kw.with_macro(spin_macro);
+        kw.next_back().unwrap()
+    }
+
+    fn assert_oklch_hue_offsets(spin_macro: SpinMacro, offsets: &[f32]) {
+        let base = HslColor::new(200, 30, 60);
+        let mut kw = KolorWheel::new(base, offsets.len());
+        kw.with_space(SpinSpace::Oklch);
+        kw.with_macro(spin_macro);
+
+        let base_hue = OklchColor::from(base).h;
+        for (color, offset) in kw.zip(offsets) {
+            let hue = OklchColor::from(color).h;
+            let diff = (hue - base_hue - offset + 180.0).rem_euclid(360.0) - 180.0;
+            assert_float_absolute_eq!(diff, 0.0, 0.5);
+        }
+    }
+
+    #[test]
+    fn spin_oklch_complementary() {
+        assert_oklch_hue_offsets(SpinMacro::Complementary, &[0.0, 180.0]);
+    }
+
+    #[test]
+    fn spin_oklch_split_complementary() {
+        assert_oklch_hue_offsets(SpinMacro::SplitComplementary(30), &[0.0, 150.0, 210.0]);
+    }
+
+    #[test]
+    fn spin_oklch_triadic() {
+        assert_oklch_hue_offsets(SpinMacro::Triadic, &[0.0, 120.0, 240.0]);
+    }
+
+    #[test]
+    fn spin_oklch_tetradic() {
+        assert_oklch_hue_offsets(SpinMacro::Tetradic, &[0.0, 90.0, 180.0, 270.0]);
+    }
+
+    #[test]
+    fn spin_oklch_rectangle() {
+        assert_oklch_hue_offsets(SpinMacro::Rectangle(60), &[0.0, 60.0, 180.0, 240.0]);
+    }
+
+    #[test]
+    fn spin_oklch_analogous() {
+        assert_oklch_hue_offsets(SpinMacro::Analogous(30), &[0.0, -30.0, 30.0]);
+    }
+
+    #[test]
+    fn spin_oklch_gradient_colors() {
+        let stops = vec![HslColor::new(30, 80, 40), HslColor::new(120, 50, 70)];
+        let last = oklch_macro_last(HslColor::new(200, 60, 70), SpinMacro::GradientColors(stops));
+        assert_eq!(RgbColor::from(last), RgbColor { r: 140, g: 217, b: 140 });
+    }
+
+    #[test]
+    fn spin_oklch_gradient_colors_at() {
+        let stops = vec![(HslColor::new(30, 80, 40), 0.5), (HslColor::new(120, 50, 70), 1.0)];
+        let mut kw = KolorWheel::new(HslColor::new(200, 60, 70), 5);
+        kw.with_space(SpinSpace::Oklch);
+        kw.with_macro(SpinMacro::GradientColorsAt(stops));
+        let middle = kw.get(2).unwrap();
+        assert_eq!(RgbColor::from(middle), RgbColor { r: 184, g: 102, b: 20 });
+    }
+
+    #[test]
+    fn spin_oklch_gradient_color_exact_target() {
+        let target = HslColor::new(30, 80, 40);
+        let last = oklch_macro_last(HslColor::new(200, 60, 70), SpinMacro::GradientColor(target));
+        assert_eq!(RgbColor::from(last), RgbColor::from(target));
+    }
+
+    #[test]
+    fn spin_oklch_fade_to_gray() {
+        let last = oklch_macro_last(HslColor::new(200, 60, 70), SpinMacro::FadeToGray(40));
+        assert_float_absolute_eq!(last.s, 0.0, 0.5);
+        assert_float_absolute_eq!(last.l, 40.0, 0.1);
+    }
+
+    #[test]
+    fn spin_oklch_fade_to_black() {
+        let last = oklch_macro_last(HslColor::new(200, 60, 70), SpinMacro::FadeToBlack);
+        assert_float_absolute_eq!(last.l, 0.0, 0.1);
+    }
+
+    #[test]
+    fn spin_oklch_fade_to_white() {
+        let last = oklch_macro_last(HslColor::new(200, 60, 30), SpinMacro::FadeToWhite);
+        assert_float_absolute_eq!(last.s, 0.0, 0.5);
+        assert_float_absolute_eq!(last.l, 100.0, 0.1);
+    }
+
 }
@@ -0,0 +1,40 @@
+/// Decode a gamma-encoded sRGB channel (0..=1) to linear light
+pub(crate) fn srgb_to_linear(value: f32) -> f32 {
+
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encode a linear light channel (0..=1) to gamma-encoded sRGB
+pub(crate) fn linear_to_srgb(value: f32) -> f32 {
+
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    #[test]
+    fn linear_rgb_roundtrip() {
+        for i in 0..=20 {
+            let value = i as f32 / 20.0;
+            let result = linear_to_srgb(srgb_to_linear(value));
+            assert_float_absolute_eq!(result, value, 1e-5);
+        }
+    }
+
+    #[test]
+    fn linear_rgb_mid_gray() {
+        assert_float_absolute_eq!(srgb_to_linear(0.5), 0.214, 1e-3);
+    }
+
+}
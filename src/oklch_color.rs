@@ -0,0 +1,35 @@
+use std::convert::From;
+
+/// OKLCH representation of a color,
+/// the polar form of the perceptually uniform OKLab color space
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OklchColor {   
+    /// - `l`: perceived lightness, 0..=1
+    pub l: f32, 
+    /// - `c`: chroma, 0..0.4 (sRGB colors stay below 0.33)
+    pub c: f32, 
+    /// - `h`: hue - degrees, 0..360, but it's okay to overflow/underflow
+    pub h: f32,
+}
+
+impl From<(f32, f32, f32)> for OklchColor {
+    fn from((l, c, h): (f32, f32, f32)) -> OklchColor {
+        OklchColor { l, c, h }
+    }
+}
+
+impl From<[f32; 3]> for OklchColor {
+    fn from([l, c, h]: [f32; 3]) -> OklchColor {
+        OklchColor { l, c, h }
+    }
+}
+
+impl OklchColor {
+
+    /// Constructor with lightness, chroma and hue
+    pub fn new(l: f32, c: f32, h: f32) -> Self {                
+        Self { l, c, h }
+    }
+
+}
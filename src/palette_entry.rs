@@ -0,0 +1,141 @@
+use crate::{ KolorWheel, HslColor };
+
+/// Color of the palette with its position in the fork tree
+#[derive(Clone, PartialEq, Debug)]
+pub struct PaletteEntry {
+    /// Index of the color in the flat series
+    pub index: usize,
+    /// Position at each level, from the base level to the top (last fork),
+    /// e.g. `[row, column]` with one fork
+    pub path: Vec<usize>,
+    /// The emitted color
+    pub color: HslColor,
+    /// Colors of the lower levels the color is derived from,
+    /// from the base level, one less than the path
+    pub parents: Vec<HslColor>,
+    /// Whether the color reaches the ratio of 
+    /// [`with_min_contrast()`](KolorWheel::with_min_contrast),
+    /// `None` if no minimum contrast is set
+    pub contrast_met: Option<bool>,
+}
+
+/// Iterator, which emits [`PaletteEntry`](PaletteEntry) objects,
+/// see [`KolorWheel::entries()`](KolorWheel::entries)
+pub struct EntryIter<'k> {
+    kolor_wheel: &'k KolorWheel,
+    index: usize,
+}
+
+impl Iterator for EntryIter<'_> {
+    type Item = PaletteEntry;
+
+    fn next(&mut self) -> Option<PaletteEntry> {
+        let entry = self.kolor_wheel.get_entry(self.index)?;
+        self.index += 1;
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.kolor_wheel.total_count().saturating_sub(self.index);
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for EntryIter<'_> {}
+
+impl KolorWheel {
+
+    /// Iterate all colors with their position in the fork tree,
+    /// and the parent colors;
+    /// regardless of the iteration state:
+    /// ```
+    /// # use kolorwheel::{ KolorWheel, HslColor, SpinMode };
+    /// # let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 3);
+    /// # kw.with_hue(SpinMode::RelativeExcl(360));
+    /// # kw.fork(4);
+    /// for entry in kw.entries() {
+    ///     let [row, col] = entry.path[..] else { continue };
+    ///     println!("{} {}: {}", row, col, entry.color);
+    /// }
+    /// ```
+    pub fn entries(&self) -> EntryIter<'_> {
+        EntryIter { kolor_wheel: self, index: 0 }
+    }
+
+    /// Get the color at the index with its position in the fork tree
+    pub fn get_entry(&self, index: usize) -> Option<PaletteEntry> {
+
+        let mut levels = self.spin_levels_at(index)?;
+        let path = levels.iter().map(|&(counter, _)| counter).collect();
+        let (_, color) = levels.pop()?;
+        let (color, contrast_met) = self.apply_stages_reporting(color);
+        let parents = levels.into_iter()
+            .map(|(_, color)| HslColor::from(self.apply_stages(color)))
+            .collect()
+        ;
+
+        Some(PaletteEntry { index, path, color: HslColor::from(color), parents, contrast_met })
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ SpinMode, RgbColor };
+
+    #[test]
+    fn entries_grid_paths() {
+
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 3);
+        kw.with_hue(SpinMode::RelativeExcl(360));
+        kw.fork(4);
+        kw.with_lightness(SpinMode::Absolute(90));
+
+        let colors: Vec<HslColor> = KolorWheel::new(HslColor::new(0, 100, 50), 3)
+            .with_hue(SpinMode::RelativeExcl(360))
+            .collect()
+        ;
+
+        let entries: Vec<PaletteEntry> = kw.entries().collect();
+        assert_eq!(entries.len(), 12);
+
+        for (index, entry) in entries.iter().enumerate() {
+            assert_eq!(entry.index, index);
+            assert_eq!(entry.path, vec![index / 4, index % 4]);
+            assert_eq!(Some(entry.color), kw.get(index));
+            assert_eq!(entry.parents, vec![colors[index / 4]]);
+        }
+    }
+
+    #[test]
+    fn entries_single_level() {
+        let kw = KolorWheel::new(HslColor::new(0, 100, 50), 2);
+        let entry = kw.get_entry(1).unwrap();
+        assert_eq!(entry.path, vec![1]);
+        assert!(entry.parents.is_empty());
+        assert_eq!(entry.contrast_met, None);
+        assert_eq!(kw.get_entry(2), None);
+    }
+
+    #[test]
+    fn entries_contrast_met() {
+        let mut kw = KolorWheel::new(HslColor::new(0, 100, 50), 2);
+        kw.with_hue(SpinMode::RelativeExcl(360));
+        kw.with_min_contrast(HslColor::new(0, 0, 50), 4.5);
+        assert!(kw.entries().all(|entry| entry.contrast_met == Some(true)));
+        kw.with_min_contrast(HslColor::new(0, 0, 50), 7.0);
+        assert!(kw.entries().all(|entry| entry.contrast_met == Some(false)));
+    }
+
+    #[test]
+    fn entries_contrast_met_quantized() {
+        let background = HslColor::new(0, 0, 10);
+        let mut kw = KolorWheel::new(HslColor::new(0, 0, 0), 1);
+        kw.with_min_contrast(background, 7.0);
+        let entry = kw.get_entry(0).unwrap();
+        assert_eq!(entry.contrast_met, Some(true));
+        assert!(RgbColor::from(entry.color).contrast_ratio(RgbColor::from(background)) >= 7.0);
+    }
+
+}
@@ -0,0 +1,182 @@
+use crate::hsl_color::HslColor;
+use crate::delta_e::DeltaE;
+use crate::color_deficiency::ColorDeficiency;
+
+/// Pair of palette colors which are closer than the threshold
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ClosePair {
+    /// Index of the first color in the palette
+    pub first: usize,
+    /// Index of the second color in the palette
+    pub second: usize,
+    /// Difference of the two colors
+    pub delta_e: f32,
+}
+
+/// Pairwise difference analysis of a palette, as seen with one kind of vision
+#[derive(Clone, PartialEq, Debug)]
+pub struct PaletteAnalysis {
+    /// Smallest difference between any two colors,
+    /// infinite for palettes with less than two colors
+    pub min_delta_e: f32,
+    /// Indices of the two closest colors, if any
+    pub closest_pair: Option<(usize, usize)>,
+    /// All pairs closer than the threshold, ordered by index
+    pub close_pairs: Vec<ClosePair>,
+}
+
+/// Distinguishability report of a palette, 
+/// with normal vision and with color vision deficiencies
+#[derive(Clone, PartialEq, Debug)]
+pub struct PaletteReport {
+    /// Analysis with normal vision
+    pub normal: PaletteAnalysis,
+    /// Analysis with each simulated deficiency
+    pub deficiencies: Vec<(ColorDeficiency, PaletteAnalysis)>,
+}
+
+impl PaletteAnalysis {
+
+    fn new(colors: &[HslColor], metric: DeltaE, threshold: f32) -> Self {
+
+        let mut min_delta_e = f32::INFINITY;
+        let mut closest_pair = None;
+        let mut close_pairs = Vec::new();
+
+        for first in 0..colors.len() {
+            for second in (first + 1)..colors.len() {
+
+                let delta_e = colors[first].delta_e(colors[second], metric);
+
+                if delta_e < min_delta_e {
+                    min_delta_e = delta_e;
+                    closest_pair = Some((first, second));
+                }
+                if delta_e < threshold {
+                    close_pairs.push(ClosePair { first, second, delta_e });
+                }
+            }
+        }
+
+        Self { min_delta_e, closest_pair, close_pairs }
+    }
+
+}
+
+impl PaletteReport {
+
+    /// Analyse palette with normal vision, and with
+    /// protanopia, deuteranopia and tritanopia
+    pub fn new<I>(colors: I, metric: DeltaE, threshold: f32) -> Self 
+    where I: IntoIterator<Item = HslColor> {
+
+        let deficiencies = [
+            ColorDeficiency::Protanopia,
+            ColorDeficiency::Deuteranopia,
+            ColorDeficiency::Tritanopia,
+        ];
+        Self::with_deficiencies(colors, metric, threshold, &deficiencies)
+    }
+
+    /// Analyse palette with normal vision, and with the listed deficiencies
+    pub fn with_deficiencies<I>(colors: I, metric: DeltaE, threshold: f32, deficiencies: &[ColorDeficiency]) -> Self 
+    where I: IntoIterator<Item = HslColor> {
+
+        let colors: Vec<HslColor> = colors.into_iter().collect();
+        let normal = PaletteAnalysis::new(&colors, metric, threshold);
+
+        let deficiencies = deficiencies.iter()
+            .map(|&deficiency| {
+                let simulated: Vec<HslColor> = colors.iter()
+                    .map(|color| color.simulate_deficiency(deficiency))
+                    .collect()
+                ;
+                (deficiency, PaletteAnalysis::new(&simulated, metric, threshold))
+            })
+            .collect()
+        ;
+
+        Self { normal, deficiencies }
+    }
+
+    /// True if no pair is closer than the threshold, with any vision
+    pub fn is_distinguishable(&self) -> bool {
+        self.normal.close_pairs.is_empty() 
+            && self.deficiencies.iter().all(|(_, analysis)| analysis.close_pairs.is_empty())
+    }
+
+    /// Smallest difference between any two colors, with any vision
+    pub fn min_delta_e(&self) -> f32 {
+        self.deficiencies.iter()
+            .map(|(_, analysis)| analysis.min_delta_e)
+            .fold(self.normal.min_delta_e, f32::min)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ KolorWheel, SpinMode };
+
+    #[test]
+    fn palette_report_distinct() {
+        let palette = [
+            HslColor::new(0, 0, 0),
+            HslColor::new(0, 0, 50),
+            HslColor::new(0, 0, 100),
+        ];
+        let report = PaletteReport::new(palette, DeltaE::Ciede2000, 10.0);
+        assert!(report.is_distinguishable());
+        assert_eq!(report.normal.closest_pair, Some((1, 2)));
+        assert_eq!(report.deficiencies.len(), 3);
+    }
+
+    #[test]
+    fn palette_report_close_pair() {
+        let palette = [
+            HslColor::new(0, 100, 50),
+            HslColor::new(120, 100, 50),
+            HslColor::new(2, 100, 50),
+        ];
+        let report = PaletteReport::new(palette, DeltaE::Ciede2000, 5.0);
+        assert!(!report.is_distinguishable());
+        assert_eq!(report.normal.close_pairs.len(), 1);
+        assert_eq!(report.normal.close_pairs[0].first, 0);
+        assert_eq!(report.normal.close_pairs[0].second, 2);
+        assert_eq!(report.normal.closest_pair, Some((0, 2)));
+    }
+
+    #[test]
+    fn palette_report_deficiency_only() {
+        let palette = [
+            HslColor::new(0, 60, 45),
+            HslColor::new(90, 60, 30),
+        ];
+        let report = PaletteReport::with_deficiencies(
+            palette, DeltaE::Ciede2000, 10.0, &[ColorDeficiency::Deuteranopia],
+        );
+        assert!(report.normal.close_pairs.is_empty());
+        assert!(!report.deficiencies[0].1.close_pairs.is_empty());
+        assert!(!report.is_distinguishable());
+        assert!(report.min_delta_e() < report.normal.min_delta_e);
+    }
+
+    #[test]
+    fn palette_report_single_color() {
+        let report = PaletteReport::new([HslColor::new(0, 0, 0)], DeltaE::Cie76, 1.0);
+        assert!(report.is_distinguishable());
+        assert_eq!(report.normal.closest_pair, None);
+        assert!(report.normal.min_delta_e.is_infinite());
+    }
+
+    #[test]
+    fn palette_report_kolorwheel() {
+        let base = HslColor::new(0, 80, 50);
+        let mut kw = KolorWheel::new(base, 6);
+        kw.with_hue(SpinMode::RelativeExcl(360));
+        let report = PaletteReport::new(kw, DeltaE::Ciede2000, 5.0);
+        assert!(report.normal.close_pairs.is_empty());
+    }
+
+}
@@ -0,0 +1,212 @@
+use crate::{ KolorWheel, HslaColor, SpinMode, SpinMacro, SpinSpace, Easing, HueInterpolation, Boundary };
+
+/// Owned counterpart of [`SpinMode`](SpinMode), for storing recipes
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum SpinRecipe {
+    /// See [`SpinMode::Absolute`](SpinMode::Absolute)
+    Absolute(i32),
+    /// See [`SpinMode::RelativeIncl`](SpinMode::RelativeIncl)
+    RelativeIncl(i32),
+    /// See [`SpinMode::RelativeExcl`](SpinMode::RelativeExcl)
+    RelativeExcl(i32),
+    /// See [`SpinMode::OffsetVec`](SpinMode::OffsetVec)
+    Offset(Vec<f32>),
+}
+
+impl SpinRecipe {
+
+    /// Convert to [`SpinMode`](SpinMode)
+    pub fn to_spin_mode(&self) -> SpinMode<'static> {
+
+        match self {
+            SpinRecipe::Absolute(value) => SpinMode::Absolute(*value),
+            SpinRecipe::RelativeIncl(value) => SpinMode::RelativeIncl(*value),
+            SpinRecipe::RelativeExcl(value) => SpinMode::RelativeExcl(*value),
+            SpinRecipe::Offset(offsets) => SpinMode::OffsetVec(offsets.clone()),
+        }
+    }
+
+}
+
+/// Settings of one level of [`KolorWheel`](KolorWheel):
+/// the base level or a fork
+#[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct LevelRecipe {
+    /// Number of spin steps
+    pub count: usize,
+    /// Color space of spinning, forks inherit it by default
+    pub space: Option<SpinSpace>,
+    /// Spin macros, applied before the channel spins
+    pub macros: Vec<SpinMacro>,
+    /// Spins of Hue channel, e.g. one calculated and one offset
+    pub hue: Vec<SpinRecipe>,
+    /// Spins of Saturation channel
+    pub saturation: Vec<SpinRecipe>,
+    /// Spins of Lightness channel
+    pub lightness: Vec<SpinRecipe>,
+    /// Spins of Alpha channel
+    pub alpha: Vec<SpinRecipe>,
+    /// Easing of the calculated Hue spin
+    pub hue_easing: Easing,
+    /// Easing of the calculated Saturation spin
+    pub saturation_easing: Easing,
+    /// Easing of the calculated Lightness spin
+    pub lightness_easing: Easing,
+    /// Easing of the calculated Alpha spin
+    pub alpha_easing: Easing,
+    /// Direction of the Hue spin towards absolute target
+    pub hue_interpolation: HueInterpolation,
+    /// Behaviour of Saturation channel beyond 0..=100 range
+    pub saturation_boundary: Boundary,
+    /// Behaviour of Lightness channel beyond 0..=100 range
+    pub lightness_boundary: Boundary,
+}
+
+/// Owned description of a palette: base color,
+/// the settings of the base level and the forks
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PaletteRecipe {
+    /// Base color, its alpha is the base of the alpha spin
+    pub color: HslaColor,
+    /// Settings of the base level
+    pub level: LevelRecipe,
+    /// Settings of the forked levels, in order
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub forks: Vec<LevelRecipe>,
+}
+
+impl LevelRecipe {
+
+    pub(crate) fn apply(&self, kw: &mut KolorWheel) {
+
+        if let Some(space) = self.space {
+            kw.with_space(space);
+        }
+        kw.with_hue_interpolation(self.hue_interpolation);
+        kw.with_saturation_boundary(self.saturation_boundary);
+        kw.with_lightness_boundary(self.lightness_boundary);
+
+        for spin_macro in &self.macros {
+            kw.with_macro(spin_macro.clone());
+        }
+        for spin in &self.hue {
+            kw.with_hue(spin.to_spin_mode());
+        }
+        for spin in &self.saturation {
+            kw.with_saturation(spin.to_spin_mode());
+        }
+        for spin in &self.lightness {
+            kw.with_lightness(spin.to_spin_mode());
+        }
+        for spin in &self.alpha {
+            kw.with_alpha(spin.to_spin_mode());
+        }
+
+        kw.with_hue_easing(self.hue_easing);
+        kw.with_saturation_easing(self.saturation_easing);
+        kw.with_lightness_easing(self.lightness_easing);
+        kw.with_alpha_easing(self.alpha_easing);
+    }
+
+}
+
+impl PaletteRecipe {
+
+    /// Create recipe with base color and number of spin steps,
+    /// without any spin
+    pub fn new<T>(color: T, count: usize) -> Self
+    where T: Into<HslaColor> {
+        Self {
+            color: color.into(),
+            level: LevelRecipe { count, ..LevelRecipe::default() },
+            forks: Vec::new(),
+        }
+    }
+
+    /// Build the [`KolorWheel`](KolorWheel) object described by the recipe
+    pub fn to_kolor_wheel(&self) -> KolorWheel {
+
+        let mut kw = KolorWheel::new(self.color, self.level.count);
+        self.level.apply(&mut kw);
+
+        for fork in &self.forks {
+            kw.fork(fork.count);
+            fork.apply(&mut kw);
+        }
+
+        kw
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HslColor;
+
+    #[test]
+    fn recipe_same_as_builder() {
+
+        let base = HslColor::new(0, 100, 50);
+
+        let mut recipe = PaletteRecipe::new(base, 3);
+        recipe.level.hue.push(SpinRecipe::RelativeExcl(360));
+        recipe.level.lightness.push(SpinRecipe::Offset(vec![0.0, -10.0]));
+        recipe.forks.push(LevelRecipe {
+            count: 4,
+            macros: vec![SpinMacro::FadeToWhite],
+            lightness_easing: Easing::EaseOut,
+            ..LevelRecipe::default()
+        });
+
+        let mut kw = KolorWheel::new(base, 3);
+        kw.with_hue(SpinMode::RelativeExcl(360));
+        kw.with_lightness(SpinMode::Offset(&[0, -10]));
+        kw.fork(4);
+        kw.with_macro(SpinMacro::FadeToWhite);
+        kw.with_lightness_easing(Easing::EaseOut);
+
+        let expected: Vec<HslColor> = kw.collect();
+        let result: Vec<HslColor> = recipe.to_kolor_wheel().collect();
+        assert_eq!(result.len(), 12);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn recipe_base_alpha() {
+
+        let mut recipe = PaletteRecipe::new(HslaColor::new(0, 100, 50, 40), 3);
+        recipe.level.alpha.push(SpinRecipe::RelativeIncl(40));
+
+        let result: Vec<HslaColor> = recipe.to_kolor_wheel().hsla_iter().collect();
+        assert_eq!(result[0].a, 40.0);
+        assert_eq!(result[2].a, 80.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn recipe_serde_roundtrip() {
+
+        let json = r##"{
+            "color": "#ff0000",
+            "level": { "count": 3, "hue": [ { "RelativeExcl": 360 } ] },
+            "forks": [
+                { "count": 4, "space": "Oklch", "macros": [ { "FadeToGray": 80 } ] }
+            ]
+        }"##;
+
+        let recipe: PaletteRecipe = serde_json::from_str(json).unwrap();
+        assert_eq!(recipe.forks[0].space, Some(SpinSpace::Oklch));
+        assert_eq!(recipe.to_kolor_wheel().count(), 12);
+
+        let text = serde_json::to_string(&recipe).unwrap();
+        let result: PaletteRecipe = serde_json::from_str(&text).unwrap();
+        assert_eq!(result, recipe);
+    }
+
+}
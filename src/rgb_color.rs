@@ -1,5 +1,7 @@
 use std::convert::From;
+use std::fmt;
 use std::str::FromStr;
+use crate::css_parser::parse_css_color;
 
 /// RGB representation of a color, which
 /// can be implicitly converted (`From/Into`) to [`RgbColor`]
@@ -49,81 +51,172 @@ impl From<&[f32; 3]> for RgbColor {
     }
 }
 
-#[derive(Debug)]
-/// Possible errors when parsing [`HslColor`](`crate::HslColor`) from hex `&str`
+/// Possible errors when parsing [`RgbColor`](`crate::RgbColor`) from `&str`,
+/// positions are byte offsets in the parsed string
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
 pub enum ParseError {
-    /// Only `RRGGBB` or `RGB` variants are accepted, with optional "`#`" prefix.
+    /// Only `RRGGBB`, `RGB`, `RRGGBBAA` or `RGBA` variants are accepted, 
+    /// with optional "`#`" prefix.
     /// Reporting invalid length value
     InvalidLength(usize),
     /// Only hexadecimal digits can be used, any case.
-    /// Reporting invalid character (first one)
-    InvalidDigit(u8),
+    /// Reporting invalid character (first one) and its position
+    InvalidDigit(u8, usize),
+    /// Unknown color name or function
+    UnknownName(usize),
+    /// Known, but unsupported color format, 
+    /// e.g. `color()`, `color-mix()` or `currentcolor`
+    UnsupportedFormat(usize),
+    /// Invalid number, unit or keyword for the component
+    InvalidValue(usize),
+    /// Unexpected character, e.g. missing separator or parenthesis
+    UnexpectedCharacter(usize),
+    /// The string ended before the color was complete
+    UnexpectedEnd(usize),
 }
 
-/// Create RGB color from hex `str`:
-/// - "`#`" prefix is optional
-/// - both `RGB` and `RRGGBB` format is accepted
+impl ParseError {
+
+    /// Position (byte offset) of the failure, if it's known
+    pub fn position(&self) -> Option<usize> {
+        match *self {
+            ParseError::InvalidLength(_) => None,
+            ParseError::InvalidDigit(_, position)
+            | ParseError::UnknownName(position)
+            | ParseError::UnsupportedFormat(position)
+            | ParseError::InvalidValue(position)
+            | ParseError::UnexpectedCharacter(position)
+            | ParseError::UnexpectedEnd(position) => Some(position),
+        }
+    }
+
+    /// Shift the position, when a substring was parsed
+    pub(crate) fn with_offset(self, offset: usize) -> Self {
+        match self {
+            ParseError::InvalidLength(len) => ParseError::InvalidLength(len),
+            ParseError::InvalidDigit(digit, position) => ParseError::InvalidDigit(digit, position + offset),
+            ParseError::UnknownName(position) => ParseError::UnknownName(position + offset),
+            ParseError::UnsupportedFormat(position) => ParseError::UnsupportedFormat(position + offset),
+            ParseError::InvalidValue(position) => ParseError::InvalidValue(position + offset),
+            ParseError::UnexpectedCharacter(position) => ParseError::UnexpectedCharacter(position + offset),
+            ParseError::UnexpectedEnd(position) => ParseError::UnexpectedEnd(position + offset),
+        }
+    }
+
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParseError::InvalidLength(len) => {
+                write!(f, "invalid hex color length {}, expected 3, 4, 6 or 8 digits", len)
+            },
+            ParseError::InvalidDigit(digit, position) => {
+                write!(f, "invalid hex digit {:?} at position {}", char::from(digit), position)
+            },
+            ParseError::UnknownName(position) => {
+                write!(f, "unknown color name or function at position {}", position)
+            },
+            ParseError::UnsupportedFormat(position) => {
+                write!(f, "unsupported color format at position {}", position)
+            },
+            ParseError::InvalidValue(position) => {
+                write!(f, "invalid value at position {}", position)
+            },
+            ParseError::UnexpectedCharacter(position) => {
+                write!(f, "unexpected character at position {}", position)
+            },
+            ParseError::UnexpectedEnd(position) => {
+                write!(f, "unexpected end of color at position {}", position)
+            },
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Create RGB color from `str`:
+/// - hex format, "`#`" prefix is optional,
+///   both `RGB` and `RRGGBB` format is accepted
+/// - CSS Color Level 4 syntax, see [`RgbaColor`](crate::RgbaColor)
+/// - `RGBA` and `RRGGBBAA` formats, as well as CSS colors 
+///   are also accepted, but alpha is dropped,
+///   use [`RgbaColor`](crate::RgbaColor) to keep it
 impl FromStr for RgbColor {
     type Err = ParseError;
 
-    fn from_str(hex: &str) -> Result<RgbColor, Self::Err> {
-        Self::try_parse_hex_auto(hex)
+    fn from_str(text: &str) -> Result<RgbColor, Self::Err> {
+        let [r, g, b, _] = parse_css_color(text)?;
+        Ok(RgbColor::from([r, g, b]))
     }
 }
 
 impl RgbColor {
 
-    fn try_parse_hex_auto(hex: &str) -> Result<RgbColor, ParseError> {
+    /// Unquantized channels in 0..=1 range
+    pub(crate) fn to_rgb_f32(self) -> [f32; 3] {
+        [self.r, self.g, self.b].map(|channel| f32::from(channel) / 255.0)
+    }
+
+    pub(crate) fn try_parse_hex_with_alpha(hex: &str) -> Result<[u8; 4], ParseError> {
 
         let mut hexb = hex.as_bytes();
+        let mut prefix = 0;
 
-        if hexb.is_empty() {
-            return Err(ParseError::InvalidLength(0));
+        if hexb.first() == Some(&b'#') { 
+            hexb = &hexb[1..]; 
+            prefix = 1;
         }
-
-        if hexb[0] == b'#' { hexb = &hexb[1..]; }
         let len = hexb.len();
 
-        match len {
-            3 => Self::parse_hex_6_digits(&[hexb[0], hexb[0], hexb[1], hexb[1], hexb[2], hexb[2]]),
-            6 => Self::parse_hex_6_digits(hexb),
-            _ => Err(ParseError::InvalidLength(len)),
+        if !matches!(len, 3 | 4 | 6 | 8) {
+            return Err(ParseError::InvalidLength(len));
+        }
+
+        if let Some(index) = hexb.iter().position(|digit| !digit.is_ascii_hexdigit()) {
+            return Err(ParseError::InvalidDigit(hexb[index], prefix + index));
         }
+
+        let result = match len {
+            3 => Self::parse_hex_8_digits(&[hexb[0], hexb[0], hexb[1], hexb[1], hexb[2], hexb[2], b'F', b'F']),
+            4 => Self::parse_hex_8_digits(&[hexb[0], hexb[0], hexb[1], hexb[1], hexb[2], hexb[2], hexb[3], hexb[3]]),
+            6 => Self::parse_hex_8_digits(&[hexb[0], hexb[1], hexb[2], hexb[3], hexb[4], hexb[5], b'F', b'F']),
+            _ => Self::parse_hex_8_digits(hexb),
+        };
+
+        Ok(result)
     }
 
-    fn parse_hex_6_digits(hexb: &[u8]) -> Result<RgbColor, ParseError> {
+    /// Digits should be validated
+    fn parse_hex_8_digits(hexb: &[u8]) -> [u8; 4] {
 
-        let r_hi = Self::parse_hex_digit(hexb[0])?;
-        let r_lo = Self::parse_hex_digit(hexb[1])?;
-        let g_hi = Self::parse_hex_digit(hexb[2])?;
-        let g_lo = Self::parse_hex_digit(hexb[3])?;
-        let b_hi = Self::parse_hex_digit(hexb[4])?;
-        let b_lo = Self::parse_hex_digit(hexb[5])?;
+        let mut result = [0; 4];
 
-        let r = (r_hi << 4) + r_lo;
-        let g = (g_hi << 4) + g_lo;
-        let b = (b_hi << 4) + b_lo;
+        for (channel, digits) in result.iter_mut().zip(hexb.chunks(2)) {
+            let hi = Self::parse_hex_digit(digits[0]);
+            let lo = Self::parse_hex_digit(digits[1]);
+            *channel = (hi << 4) + lo;
+        }
 
-        Ok(RgbColor { r, g, b })
+        result
     }
 
-    fn parse_hex_digit(digit: u8) -> Result<u8, ParseError> {
+    fn parse_hex_digit(digit: u8) -> u8 {
 
         if digit.is_ascii_digit() {
-            return Ok(digit - b'0');
+            return digit - b'0';
         }
         if (b'a'..=b'f').contains(&digit) {
-            return Ok(10 + digit - b'a');
-        }
-        if (b'A'..=b'F').contains(&digit) {
-            return Ok(10 + digit - b'A');
+            return 10 + digit - b'a';
         }
 
-        Err(ParseError::InvalidDigit(digit))
+        10 + digit - b'A'
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::redundant_pattern_matching)]
 mod tests {
     use super::*;
 
@@ -157,6 +250,14 @@ mod tests {
         assert_eq!(rgb.b, 0x44);
     }
 
+    #[test]
+    fn rgb_hex_with_alpha() {
+        let rgb = RgbColor::from_str("#d498ea80").unwrap();
+        assert_eq!(rgb, RgbColor { r: 0xD4, g: 0x98, b: 0xEA });
+        let rgb = RgbColor::from_str("C348").unwrap();
+        assert_eq!(rgb, RgbColor { r: 0xCC, g: 0x33, b: 0x44 });
+    }
+
     #[test]
     fn rgb_hex_invalid_length() {
         let rgb_result = RgbColor::from_str("#21");
@@ -166,7 +267,24 @@ mod tests {
     #[test]
     fn rgb_hex_invalid_digit() {
         let rgb_result = RgbColor::from_str("12345G");
-        assert!(matches!(rgb_result, Err(ParseError::InvalidDigit(b'G'))));
+        assert!(matches!(rgb_result, Err(ParseError::InvalidDigit(b'G', 5))));
+    }
+
+    #[test]
+    fn rgb_hex_invalid_digit_position() {
+        let rgb_result = RgbColor::from_str("  #1x2");
+        assert_eq!(rgb_result, Err(ParseError::InvalidDigit(b'x', 4)));
+        assert_eq!(rgb_result.unwrap_err().position(), Some(4));
+    }
+
+    #[test]
+    fn rgb_parse_error_message() {
+        let err = RgbColor::from_str("#12345G").unwrap_err();
+        assert_eq!(err.to_string(), "invalid hex digit 'G' at position 6");
+        let err = RgbColor::from_str("#21").unwrap_err();
+        assert_eq!(err.to_string(), "invalid hex color length 2, expected 3, 4, 6 or 8 digits");
+        let err: Box<dyn std::error::Error> = Box::new(err);
+        assert!(err.to_string().contains("length 2"));
     }
 
     #[test]
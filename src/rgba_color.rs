@@ -0,0 +1,163 @@
+use std::convert::From;
+use std::str::FromStr;
+use crate::rgb_color::{ RgbColor, ParseError };
+use crate::css_parser::parse_css_color;
+
+/// RGB representation of a color with alpha channel
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub struct RgbaColor {
+    /// Red channel (0..=255)
+    pub r: u8,  
+    /// Green channel (0..=255)
+    pub g: u8,  
+    /// Blue channel (0..=255)
+    pub b: u8,  
+    /// Alpha channel (0..=255), 0 is transparent, 255 is opaque
+    pub a: u8,  
+}
+
+impl From<(u8, u8, u8, u8)> for RgbaColor {
+    fn from((r, g, b, a): (u8, u8, u8, u8)) -> RgbaColor {
+        RgbaColor { r, g, b, a }
+    }
+}
+
+impl From<[u8; 4]> for RgbaColor {
+    fn from(rgba: [u8; 4]) -> RgbaColor {
+        RgbaColor { r: rgba[0], g: rgba[1], b: rgba[2], a: rgba[3] }
+    }
+}
+
+impl From<[f32; 4]> for RgbaColor {
+    fn from([r, g, b, a]: [f32; 4]) -> Self {
+        let RgbColor { r, g, b } = RgbColor::from([r, g, b]);
+        let a = f32::round(a.clamp(0.0, 1.0) * 255.0) as u8;
+        RgbaColor { r, g, b, a }
+    }
+}
+
+/// Opaque color
+impl From<RgbColor> for RgbaColor {
+    fn from(RgbColor { r, g, b }: RgbColor) -> RgbaColor {
+        RgbaColor { r, g, b, a: 255 }
+    }
+}
+
+/// Alpha channel is dropped
+impl From<RgbaColor> for RgbColor {
+    fn from(RgbaColor { r, g, b, .. }: RgbaColor) -> RgbColor {
+        RgbColor { r, g, b }
+    }
+}
+
+/// Create RGBA color from `str`:
+/// - hex format, "`#`" prefix is optional,
+///   `RGB`, `RGBA`, `RRGGBB` and `RRGGBBAA` formats are accepted,
+///   missing alpha means opaque
+/// - CSS Color Level 4 functions: `rgb()`, `rgba()`, `hsl()`, `hsla()`,
+///   `hwb()`, `lab()`, `lch()`, `oklab()` and `oklch()`,
+///   with comma or space syntax
+/// - CSS named colors and `transparent`
+impl FromStr for RgbaColor {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<RgbaColor, Self::Err> {
+        parse_css_color(text).map(RgbaColor::from)
+    }
+}
+
+impl RgbaColor {
+
+    /// Composite this color over the background (source-over),
+    /// in gamma-encoded sRGB, as browsers do
+    pub fn composite_over<T>(&self, background: T) -> RgbaColor 
+    where T: Into<RgbaColor> {
+
+        let background: RgbaColor = background.into();
+
+        let src_a = f32::from(self.a) / 255.0;
+        let dst_a = f32::from(background.a) / 255.0;
+        let out_a = src_a + dst_a * (1.0 - src_a);
+
+        if out_a <= 0.0 {
+            return RgbaColor::default();
+        }
+
+        let blend = |src: u8, dst: u8| {
+            let src = f32::from(src) / 255.0;
+            let dst = f32::from(dst) / 255.0;
+            (src * src_a + dst * dst_a * (1.0 - src_a)) / out_a
+        };
+
+        RgbaColor::from([
+            blend(self.r, background.r),
+            blend(self.g, background.g),
+            blend(self.b, background.b),
+            out_a,
+        ])
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rgba_hex_long() {
+        let rgba = RgbaColor::from_str("#1af9cc80").unwrap();
+        assert_eq!(rgba, RgbaColor { r: 0x1A, g: 0xF9, b: 0xCC, a: 0x80 });
+    }
+
+    #[test]
+    fn rgba_hex_short() {
+        let rgba = RgbaColor::from_str("C348").unwrap();
+        assert_eq!(rgba, RgbaColor { r: 0xCC, g: 0x33, b: 0x44, a: 0x88 });
+    }
+
+    #[test]
+    fn rgba_hex_without_alpha() {
+        let rgba = RgbaColor::from_str("#C34").unwrap();
+        assert_eq!(rgba.a, 0xFF);
+        let rgba = RgbaColor::from_str("#d498ea").unwrap();
+        assert_eq!(rgba.a, 0xFF);
+    }
+
+    #[test]
+    fn rgba_hex_invalid_length() {
+        let rgba_result = RgbaColor::from_str("#12345");
+        assert!(matches!(rgba_result, Err(ParseError::InvalidLength(5))));
+    }
+
+    #[test]
+    fn rgba_composite_half_over_white() {
+        let color = RgbaColor { r: 0, g: 0, b: 255, a: 128 };
+        let white = RgbColor { r: 255, g: 255, b: 255 };
+        let result = color.composite_over(white);
+        assert_eq!(result, RgbaColor { r: 127, g: 127, b: 255, a: 255 });
+    }
+
+    #[test]
+    fn rgba_composite_opaque() {
+        let color = RgbaColor { r: 10, g: 20, b: 30, a: 255 };
+        let background = RgbaColor { r: 200, g: 100, b: 0, a: 255 };
+        assert_eq!(color.composite_over(background), color);
+    }
+
+    #[test]
+    fn rgba_composite_transparent() {
+        let color = RgbaColor { r: 10, g: 20, b: 30, a: 0 };
+        let background = RgbaColor { r: 200, g: 100, b: 0, a: 255 };
+        assert_eq!(color.composite_over(background), background);
+    }
+
+    #[test]
+    fn rgba_composite_both_translucent() {
+        let color = RgbaColor { r: 255, g: 0, b: 0, a: 128 };
+        let background = RgbaColor { r: 0, g: 0, b: 255, a: 128 };
+        let result = color.composite_over(background);
+        assert_eq!(result.a, 192);
+        assert!(result.r > result.b);
+    }
+
+}
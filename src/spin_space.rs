@@ -0,0 +1,90 @@
+use crate::HslColor;
+use crate::OklchColor;
+
+/// Color spaces in which the H/S/L channels are spun
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum SpinSpace {
+    /// Spin hue, saturation and lightness of the HSL model (default)
+    #[default]
+    Hsl,
+    /// Spin hue, chroma and lightness of the perceptually uniform OKLCH model:
+    /// - hue is in degrees,
+    /// - saturation channel is the chroma, in percent of 0.4,
+    /// - lightness channel is the OKLCH lightness, in percent
+    Oklch,
+}
+
+/// Chroma value of the 100% on the saturation channel, as CSS defines it
+const OKLCH_CHROMA_FULL: f32 = 0.4;
+
+impl SpinSpace {
+
+    /// Express color as channel values of the spin space
+    pub(crate) fn hsl_to_channels(self, color: HslColor) -> HslColor {
+
+        match self {
+            SpinSpace::Hsl => color,
+            SpinSpace::Oklch => {
+                let oklch = OklchColor::from(color);
+                HslColor {
+                    h: oklch.h,
+                    s: oklch.c / OKLCH_CHROMA_FULL * 100.0,
+                    l: oklch.l * 100.0,
+                }
+            },
+        }
+    }
+
+    /// Turn channel values of the spin space back to color
+    pub(crate) fn channels_to_hsl(self, channels: HslColor) -> HslColor {
+
+        match self {
+            SpinSpace::Hsl => channels,
+            SpinSpace::Oklch => {
+                let oklch = OklchColor {
+                    l: channels.l / 100.0,
+                    c: channels.s / 100.0 * OKLCH_CHROMA_FULL,
+                    h: channels.h,
+                };
+                let mut color = HslColor::from(oklch);
+                color.normalize();
+                color
+            },
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    #[test]
+    fn spin_space_hsl_identity() {
+        let color = HslColor::new(200, 60, 40);
+        let channels = SpinSpace::Hsl.hsl_to_channels(color);
+        assert_eq!(channels, color);
+        assert_eq!(SpinSpace::Hsl.channels_to_hsl(channels), color);
+    }
+
+    #[test]
+    fn spin_space_oklch_white() {
+        let channels = SpinSpace::Oklch.hsl_to_channels(HslColor::new(0, 0, 100));
+        assert_float_absolute_eq!(channels.s, 0.0, 0.1);
+        assert_float_absolute_eq!(channels.l, 100.0, 0.1);
+    }
+
+    #[test]
+    fn spin_space_oklch_roundtrip() {
+        let color = HslColor::new(200, 60, 40);
+        let channels = SpinSpace::Oklch.hsl_to_channels(color);
+        let result = SpinSpace::Oklch.channels_to_hsl(channels);
+        assert_float_absolute_eq!(result.h, 200.0, 0.1);
+        assert_float_absolute_eq!(result.s, 60.0, 0.1);
+        assert_float_absolute_eq!(result.l, 40.0, 0.1);
+    }
+
+}
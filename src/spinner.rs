@@ -1,74 +1,163 @@
 use crate::SpinMode;
+use crate::SpinSpace;
+use crate::Easing;
+use crate::HueInterpolation;
+use crate::Boundary;
+use crate::boundary::RampExtent;
 use crate::HslColor;
+use crate::HslaColor;
 
 pub(crate) struct Spinner {
 
+    space: SpinSpace,
     color: HslColor,    
+    alpha: f32,
     count: usize,
     counter: usize,  
 
     spin_calculated_hue: SpinCalculated,
     spin_calculated_saturation: SpinCalculated,
     spin_calculated_lightness: SpinCalculated,
+    spin_calculated_alpha: SpinCalculated,
+
+    easing_hue: Easing,
+    easing_saturation: Easing,
+    easing_lightness: Easing,
+    easing_alpha: Easing,
+    hue_interpolation: HueInterpolation,
+    boundary_saturation: Boundary,
+    boundary_lightness: Boundary,
+    rescale_saturation: Option<RampExtent>,
+    rescale_lightness: Option<RampExtent>,
 
     spin_stored_hue: SpinStored,
     spin_stored_saturation: SpinStored,
     spin_stored_lightness: SpinStored,
+    spin_stored_alpha: SpinStored,
+
+    spin_gradient: SpinGradient,
 
 }
 
 enum SpinCalculated {
     Inactive,
+    /// Increment per step
     Active(f32),
+    /// Absolute target, the increment depends on the base color,
+    /// so it follows the parent color in forked levels
+    Target(f32),
 }
 
 enum SpinStored {
     Inactive,
-    Active(Vec<i32>),
+    Active(Vec<f32>),
+}
+
+enum SpinGradient {
+    Inactive,
+    Active(Vec<(f32, HslColor)>),
 }
 
 impl<'sp> Spinner {
 
     pub(crate) fn new<T>(color: T, count: usize) -> Self 
-    where T: Into<HslColor> {
+    where T: Into<HslaColor> {
+        let color: HslaColor = color.into();
         Self {
-            color: color.into(),
+            space: SpinSpace::Hsl,
+            color: HslColor::from(color),
+            alpha: color.a,
             count,
             counter: 0,
 
             spin_calculated_hue: SpinCalculated::Inactive,
             spin_calculated_saturation: SpinCalculated::Inactive,
             spin_calculated_lightness: SpinCalculated::Inactive,
+            spin_calculated_alpha: SpinCalculated::Inactive,
+
+            easing_hue: Easing::Linear,
+            easing_saturation: Easing::Linear,
+            easing_lightness: Easing::Linear,
+            easing_alpha: Easing::Linear,
+            hue_interpolation: HueInterpolation::Specified,
+            boundary_saturation: Boundary::Clamp,
+            boundary_lightness: Boundary::Clamp,
+            rescale_saturation: None,
+            rescale_lightness: None,
 
             spin_stored_hue: SpinStored::Inactive,
             spin_stored_saturation: SpinStored::Inactive,
             spin_stored_lightness: SpinStored::Inactive,
+            spin_stored_alpha: SpinStored::Inactive,
+
+            spin_gradient: SpinGradient::Inactive,
         }
     }
 
-    pub(crate) fn color(&self) ->  HslColor {
-        self.color
+    pub(crate) fn color(&self) ->  HslaColor {
+        HslaColor::from_hsl(self.space.channels_to_hsl(self.color), self.alpha)
+    }
+
+    pub(crate) fn space(&self) -> SpinSpace {
+        self.space
+    }
+
+    pub(crate) fn count(&self) -> usize {
+        self.count
+    }
+
+    pub(crate) fn counter(&self) -> usize {
+        self.counter
     }
 
     pub(crate) fn rewind(&mut self) -> &mut Self {
-        self.counter = 0;
+        self.seek(0)
+    }
+
+    /// Set the position, the next spin will emit the color at the index
+    pub(crate) fn seek(&mut self, index: usize) -> &mut Self {
+        self.counter = index;
         self
     }
 
-    pub(crate) fn with_color(&mut self, color: HslColor) -> &mut Self {
-        self.color = color;
+    pub(crate) fn with_color(&mut self, color: HslaColor) -> &mut Self {
+        self.color = self.space.hsl_to_channels(HslColor::from(color));
+        self.alpha = color.a;
         self
     }
 
+    pub(crate) fn with_space(&mut self, space: SpinSpace) {
+        let color = self.color();
+        self.space = space;
+        self.color = space.hsl_to_channels(HslColor::from(color));
+    }
+
+    /// Set absolute targets, in channel values of the spin space,
+    /// without rounding to integer as `SpinMode::Absolute` does
+    pub(crate) fn with_targets(&mut self, hue: Option<f32>, saturation: Option<f32>, lightness: Option<f32>) {
+
+        if let Some(target) = hue {
+            self.spin_calculated_hue = SpinCalculated::Target(target);
+        }
+        if let Some(target) = saturation {
+            self.spin_calculated_saturation = SpinCalculated::Target(target);
+        }
+        if let Some(target) = lightness {
+            self.spin_calculated_lightness = SpinCalculated::Target(target);
+        }
+
+        self.update_rescale();
+    }
+
     pub(crate) fn with_hue(&mut self, spin_mode: SpinMode<'sp>) {
 
         match spin_mode {
             SpinMode::Still => (),
-            SpinMode::Offset(_) => {
+            SpinMode::Offset(_) | SpinMode::OffsetVec(_) => {
                 self.spin_stored_hue = Self::store_spin_values(spin_mode);
             },
             _ => {
-                self.spin_calculated_hue = Self::calc_spin_value(spin_mode, self.color.h, self.count);
+                self.spin_calculated_hue = Self::calc_spin_value(spin_mode, self.count);
             },
         }        
 
@@ -79,46 +168,136 @@ impl<'sp> Spinner {
 
         match spin_mode {
             SpinMode::Still => (),
-            SpinMode::Offset(_) => {
+            SpinMode::Offset(_) | SpinMode::OffsetVec(_) => {
                 self.spin_stored_saturation = Self::store_spin_values(spin_mode);
             },
             _ => {
-                self.spin_calculated_saturation = Self::calc_spin_value(spin_mode, self.color.s, self.count);
+                self.spin_calculated_saturation = Self::calc_spin_value(spin_mode, self.count);
             },
         }        
 
+        self.update_rescale();
     }
 
     pub(crate) fn with_lightness(&mut self, spin_mode: SpinMode<'sp>) {
         
         match spin_mode {
             SpinMode::Still => (),
-            SpinMode::Offset(_) => {
+            SpinMode::Offset(_) | SpinMode::OffsetVec(_) => {
                 self.spin_stored_lightness = Self::store_spin_values(spin_mode);
             },
             _ => {
-                self.spin_calculated_lightness = Self::calc_spin_value(spin_mode, self.color.l, self.count);
+                self.spin_calculated_lightness = Self::calc_spin_value(spin_mode, self.count);
             },
         }        
 
+        self.update_rescale();
     }
 
-    fn calc_spin_value(spin_mode: SpinMode<'sp>, base_value: f32, count: usize) -> SpinCalculated {
+    pub(crate) fn with_alpha(&mut self, spin_mode: SpinMode<'sp>) {
+        
+        match spin_mode {
+            SpinMode::Still => (),
+            SpinMode::Offset(_) | SpinMode::OffsetVec(_) => {
+                self.spin_stored_alpha = Self::store_spin_values(spin_mode);
+            },
+            _ => {
+                self.spin_calculated_alpha = Self::calc_spin_value(spin_mode, self.count);
+            },
+        }        
 
-        match spin_mode { 
+    }
 
-            SpinMode::Absolute(abs_target) => {
-                let abs_target = abs_target as f32;
-                let rel_target = abs_target - base_value;
-                let step = (count - 1) as f32;
-                let inc = rel_target / step;
+    pub(crate) fn with_hue_easing(&mut self, easing: Easing) {
+        self.easing_hue = easing;
+    }
 
-                SpinCalculated::Active(inc)
-            },
+    pub(crate) fn with_saturation_easing(&mut self, easing: Easing) {
+        self.easing_saturation = easing;
+        self.update_rescale();
+    }
+
+    pub(crate) fn with_lightness_easing(&mut self, easing: Easing) {
+        self.easing_lightness = easing;
+        self.update_rescale();
+    }
+
+    pub(crate) fn with_alpha_easing(&mut self, easing: Easing) {
+        self.easing_alpha = easing;
+    }
+
+    pub(crate) fn with_hue_interpolation(&mut self, interpolation: HueInterpolation) {
+        self.hue_interpolation = interpolation;
+    }
+
+    pub(crate) fn with_saturation_boundary(&mut self, boundary: Boundary) {
+        self.boundary_saturation = boundary;
+        self.update_rescale();
+    }
+
+    pub(crate) fn with_lightness_boundary(&mut self, boundary: Boundary) {
+        self.boundary_lightness = boundary;
+        self.update_rescale();
+    }
+
+    /// Build the ramp extents for [`Boundary::Rescale`], 
+    /// should be called after each change of the spin;
+    /// rebasing needs no update, the extents are functions of the base
+    fn update_rescale(&mut self) {
+
+        let rescale_saturation = (self.boundary_saturation == Boundary::Rescale)
+            .then(|| RampExtent::new(&self.ramp_lines(|color| color.s)))
+        ;
+        let rescale_lightness = (self.boundary_lightness == Boundary::Rescale)
+            .then(|| RampExtent::new(&self.ramp_lines(|color| color.l)))
+        ;
+
+        self.rescale_saturation = rescale_saturation;
+        self.rescale_lightness = rescale_lightness;
+    }
+
+    /// Steps of the channel as `(slope, intercept)` of the base value,
+    /// the channels don't depend on each other, nor on the hue
+    fn ramp_lines(&self, channel: fn(HslColor) -> f32) -> Vec<(f32, f32)> {
+
+        let low = HslColor { h: self.color.h, s: 0.0, l: 0.0 };
+        let high = HslColor { h: self.color.h, s: 100.0, l: 100.0 };
+
+        (0..self.count)
+            .map(|counter| {
+                let at_low = channel(self.spin_unbounded_hsl(low, counter));
+                let at_high = channel(self.spin_unbounded_hsl(high, counter));
+                ((at_high - at_low) / 100.0, at_low)
+            })
+            .collect()
+    }
+
+    /// Stops are `(color, position)` pairs, position is in 0..=1,
+    /// the base color is the implicit first stop at position 0
+    pub(crate) fn with_gradient(&mut self, stops: &[(HslColor, f32)]) {
+
+        let mut last_position = 0.0;
+        let mut gradient = Vec::with_capacity(stops.len());
+
+        for &(color, position) in stops {
+            let position = position.clamp(last_position, 1.0);
+            gradient.push((position, self.space.hsl_to_channels(color)));
+            last_position = position;
+        }
+
+        self.spin_gradient = SpinGradient::Active(gradient);
+        self.update_rescale();
+    }
+
+    fn calc_spin_value(spin_mode: SpinMode<'sp>, count: usize) -> SpinCalculated {
+
+        match spin_mode { 
+
+            SpinMode::Absolute(abs_target) => SpinCalculated::Target(abs_target as f32),
 
             SpinMode::RelativeIncl(rel_target) => {
                 let rel_target = rel_target as f32;
-                let step = (count - 1) as f32;
+                let step = count.saturating_sub(1).max(1) as f32;
                 let inc = rel_target / step;
 
                 SpinCalculated::Active(inc)
@@ -126,7 +305,7 @@ impl<'sp> Spinner {
 
             SpinMode::RelativeExcl(rel_target) => {
                 let rel_target = rel_target as f32;
-                let step = count as f32;
+                let step = count.max(1) as f32;
                 let inc = rel_target / step;
 
                 SpinCalculated::Active(inc)
@@ -139,7 +318,10 @@ impl<'sp> Spinner {
     fn store_spin_values(spin_mode: SpinMode<'sp>) -> SpinStored {
 
         match spin_mode { 
-            SpinMode::Offset(values) => SpinStored::Active(Vec::from(values)),
+            SpinMode::Offset([]) => SpinStored::Inactive,
+            SpinMode::Offset(values) => SpinStored::Active(values.iter().map(|value| *value as f32).collect()),
+            SpinMode::OffsetVec(values) if values.is_empty() => SpinStored::Inactive,
+            SpinMode::OffsetVec(values) => SpinStored::Active(values),
             _ => SpinStored::Inactive,
         }
     }
@@ -148,37 +330,131 @@ impl<'sp> Spinner {
         self.counter >= self.count
     }
 
-    pub(crate) fn spin_next(&mut self) ->  HslColor {
+    pub(crate) fn spin_next(&mut self) ->  HslaColor {
 
-        if self.counter > 0 {
-            self.spin_calculated_hsl();
-        }
-        let mut offseted_color = self.spin_stored_hsl();
+        let result = self.spin_color(self.color, self.alpha, self.counter);
+        self.counter += 1;
+
+        result
+    }
+
+    /// Color at the index, without changing the position
+    pub(crate) fn spin_at(&self, index: usize) -> HslaColor {
+        self.spin_color(self.color, self.alpha, index)
+    }
+
+    /// Color at the index, as if the base color was replaced
+    pub(crate) fn spin_at_with_base(&self, base: HslaColor, index: usize) -> HslaColor {
+        let color = self.space.hsl_to_channels(HslColor::from(base));
+        self.spin_color(color, base.a, index)
+    }
+
+    fn spin_color(&self, color: HslColor, alpha: f32, counter: usize) -> HslaColor {
+
+        let mut offseted_color = self.spin_bounded_hsl(color, counter);
         offseted_color.normalize();
 
-        self.counter += 1;
-        offseted_color
+        let alpha = self.spin_calculated_channel(alpha, &self.spin_calculated_alpha, self.easing_alpha, HueInterpolation::Specified, counter);
+        let alpha = Self::spin_stored_channel(alpha, &self.spin_stored_alpha, counter);
+
+        let mut result = HslaColor::from_hsl(self.space.channels_to_hsl(offseted_color), alpha);
+        result.normalize();
+        result
+    }
+
+    fn spin_unbounded_hsl(&self, color: HslColor, counter: usize) -> HslColor {
+        let calculated_color = self.spin_calculated_hsl(color, counter);
+        self.spin_stored_hsl(calculated_color, counter)
     }
 
-    fn spin_calculated_hsl(&mut self) {
+    /// Apply boundary policy on Saturation and Lightness channels,
+    /// rescaling uses the prebuilt ramp extents
+    fn spin_bounded_hsl(&self, color: HslColor, counter: usize) -> HslColor {
 
-        Self::spin_calculated_channel(&mut self.color.h, &self.spin_calculated_hue);
-        Self::spin_calculated_channel(&mut self.color.s, &self.spin_calculated_saturation);
-        Self::spin_calculated_channel(&mut self.color.l, &self.spin_calculated_lightness);
+        let mut result = self.spin_unbounded_hsl(color, counter);
+
+        if let Some(extent) = &self.rescale_saturation {
+            let factor = extent.rescale_factor(color.s);
+            result.s = color.s + (result.s - color.s) * factor;
+        }
+        if let Some(extent) = &self.rescale_lightness {
+            let factor = extent.rescale_factor(color.l);
+            result.l = color.l + (result.l - color.l) * factor;
+        }
+
+        result.s = self.boundary_saturation.apply(result.s);
+        result.l = self.boundary_lightness.apply(result.l);
+
+        result
     }
 
-    fn spin_calculated_channel(channel_value: &mut f32, channel_spin: &SpinCalculated) {
+    fn spin_calculated_hsl(&self, color: HslColor, counter: usize) -> HslColor {
 
-        if let SpinCalculated::Active(channel_inc) = channel_spin {
-            *channel_value += channel_inc;
+        if let SpinGradient::Active(stops) = &self.spin_gradient {
+            return self.spin_gradient_hsl(color, stops, counter);
         }
+
+        let h = self.spin_calculated_channel(color.h, &self.spin_calculated_hue, self.easing_hue, self.hue_interpolation, counter);
+        let s = self.spin_calculated_channel(color.s, &self.spin_calculated_saturation, self.easing_saturation, HueInterpolation::Specified, counter);
+        let l = self.spin_calculated_channel(color.l, &self.spin_calculated_lightness, self.easing_lightness, HueInterpolation::Specified, counter);
+
+        HslColor::from((h, s, l,))
     }
 
-    fn spin_stored_hsl(&self) -> HslColor {
+    /// Interpolation is only used with absolute target, 
+    /// it should be `Specified` for channels other than Hue
+    fn spin_calculated_channel(&self, channel_value: f32, channel_spin: &SpinCalculated, easing: Easing, interpolation: HueInterpolation, counter: usize) -> f32 {
+
+        let mut channel_result = channel_value;
+
+        if counter > 0 {
+            let span = (self.count - 1) as f32;
+            let progress = easing.ease(counter as f32 / span);
+            match channel_spin {
+                SpinCalculated::Active(channel_inc) => channel_result += channel_inc * span * progress,
+                SpinCalculated::Target(target) => {
+                    channel_result += interpolation.delta(channel_value, *target) * progress;
+                },
+                SpinCalculated::Inactive => (),
+            }
+        }
+
+        channel_result
+    }
 
-        let h = Self::spin_stored_channel(self.color.h, &self.spin_stored_hue, self.counter);
-        let s = Self::spin_stored_channel(self.color.s, &self.spin_stored_saturation, self.counter);
-        let l = Self::spin_stored_channel(self.color.l, &self.spin_stored_lightness, self.counter);
+    fn spin_gradient_hsl(&self, color: HslColor, stops: &[(f32, HslColor)], counter: usize) -> HslColor {
+
+        let progress = if self.count > 1 {
+            counter as f32 / (self.count - 1) as f32
+        } else {
+            0.0
+        };
+
+        let (mut from_position, mut from_color) = (0.0, color);
+
+        for &(to_position, to_color) in stops {
+
+            if progress <= to_position {
+                let span = to_position - from_position;
+                let t = if span > 0.0 { (progress - from_position) / span } else { 1.0 };
+                return HslColor {
+                    h: from_color.h + self.hue_interpolation.delta(from_color.h, to_color.h) * t,
+                    s: from_color.s + (to_color.s - from_color.s) * t,
+                    l: from_color.l + (to_color.l - from_color.l) * t,
+                };
+            }
+
+            (from_position, from_color) = (to_position, to_color);
+        }
+
+        from_color
+    }
+
+    fn spin_stored_hsl(&self, color: HslColor, counter: usize) -> HslColor {
+
+        let h = Self::spin_stored_channel(color.h, &self.spin_stored_hue, counter);
+        let s = Self::spin_stored_channel(color.s, &self.spin_stored_saturation, counter);
+        let l = Self::spin_stored_channel(color.l, &self.spin_stored_lightness, counter);
 
         HslColor::from((h, s, l,))
     }
@@ -189,7 +465,7 @@ impl<'sp> Spinner {
 
         if let SpinStored::Active(offsets) = channel_spin {
             let index = counter % offsets.len();
-            channel_result += offsets[index] as f32;
+            channel_result += offsets[index];
         }
 
         channel_result
@@ -314,6 +590,26 @@ mod tests {
         assert_f32_near!(result.s, 100.0, 99999);        
     }
 
+    #[test]
+    fn spinner_percent_overflow_sat_boundaries() {
+
+        let color = HslColor::new(0, 90, 50);
+        let mut spinner = Spinner::new(color, 5);
+        spinner.with_saturation(SpinMode::RelativeIncl(100));
+
+        spinner.with_saturation_boundary(Boundary::Reflect);
+        assert_f32_near!(spinner.spin_at(2).s, 60.0, 99999);
+        assert_f32_near!(spinner.spin_at(4).s, 10.0, 99999);
+
+        spinner.with_saturation_boundary(Boundary::Wrap);
+        assert_f32_near!(spinner.spin_at(2).s, 40.0, 99999);
+
+        spinner.with_saturation_boundary(Boundary::Rescale);
+        assert_f32_near!(spinner.spin_at(0).s, 90.0, 99999);
+        assert_f32_near!(spinner.spin_at(2).s, 95.0, 99999);
+        assert_f32_near!(spinner.spin_at(4).s, 100.0, 99999);
+    }
+
     #[test]
     fn spinner_percent_underflow_lit() {
 
@@ -365,4 +661,247 @@ mod tests {
         assert_f32_near!(result.l, 85.0, 99999);        
     }
 
+    #[test]
+    fn spinner_easing_keeps_ends() {
+
+        let color = HslColor::new(0, 100, 10);
+        let mut spinner = Spinner::new(color, 5);
+        spinner.with_lightness(SpinMode::RelativeExcl(50));
+        spinner.with_lightness_easing(Easing::EaseIn);
+
+        let result = spinner.spin_next();
+        assert_f32_near!(result.l, 10.0, 99999);
+
+        let result = spinner.spin_next();
+        assert_gt!(result.l, 10.0);
+        assert_lt!(result.l, 20.0);
+
+        spinner.spin_next();
+        spinner.spin_next();
+
+        let result = spinner.spin_next();
+        assert_f32_near!(result.l, 50.0, 99999);
+    }
+
+    #[test]
+    fn spinner_easing_per_channel() {
+
+        let color = HslColor::new(0, 0, 0);
+        let mut spinner = Spinner::new(color, 3);
+        spinner.with_saturation(SpinMode::Absolute(100));
+        spinner.with_lightness(SpinMode::Absolute(100));
+        spinner.with_lightness_easing(Easing::Exponential(2.0));
+
+        spinner.spin_next();
+        let result = spinner.spin_next();
+        assert_f32_near!(result.s, 50.0, 99999);
+        assert_lt!(result.l, 50.0);
+    }
+
+    #[test]
+    fn spinner_gradient_stops() {
+
+        let color = HslColor::new(0, 100, 50);
+        let mut spinner = Spinner::new(color, 5);
+        spinner.with_gradient(&[
+            (HslColor::new(100, 100, 50), 0.25),
+            (HslColor::new(100, 0, 50), 1.0),
+        ]);
+
+        let result = spinner.spin_next();
+        assert_f32_near!(result.h, 0.0, 99999);
+
+        let result = spinner.spin_next();
+        assert_f32_near!(result.h, 100.0, 99999);
+        assert_f32_near!(result.s, 100.0, 99999);
+
+        let result = spinner.spin_next();
+        assert_f32_near!(result.s, 66.667, 99999);
+
+        spinner.spin_next();
+        let result = spinner.spin_next();
+        assert_f32_near!(result.h, 100.0, 99999);
+        assert_f32_near!(result.s, 0.0, 99999);
+    }
+
+    #[test]
+    fn spinner_gradient_hard_stop() {
+
+        let color = HslColor::new(0, 100, 50);
+        let mut spinner = Spinner::new(color, 3);
+        spinner.with_gradient(&[
+            (HslColor::new(0, 100, 20), 0.5),
+            (HslColor::new(0, 100, 80), 0.4),
+        ]);
+
+        spinner.spin_next();
+        let result = spinner.spin_next();
+        assert_f32_near!(result.l, 20.0, 99999);
+        let result = spinner.spin_next();
+        assert_f32_near!(result.l, 80.0, 99999);
+    }
+
+    #[test]
+    fn spinner_alpha_abs() {
+
+        let color = HslColor::new(0, 100, 50);
+        let mut spinner = Spinner::new(color, 3);
+        spinner.with_alpha(SpinMode::Absolute(0));
+
+        let result = spinner.spin_next();
+        assert_f32_near!(result.a, 100.0, 99999);
+
+        let result = spinner.spin_next();
+        assert_f32_near!(result.a, 50.0, 99999);
+
+        let result = spinner.spin_next();
+        assert_f32_near!(result.a, 0.0, 99999);
+    }
+
+    #[test]
+    fn spinner_alpha_offset_clamped() {
+
+        let color = HslaColor::new(0, 100, 50, 80);
+        let mut spinner = Spinner::new(color, 2);
+        spinner.with_alpha(SpinMode::Offset(&[40, -30]));
+
+        let result = spinner.spin_next();
+        assert_f32_near!(result.a, 100.0, 99999);
+
+        let result = spinner.spin_next();
+        assert_f32_near!(result.a, 50.0, 99999);
+    }
+
+    #[test]
+    fn spinner_spin_at_same_as_next() {
+
+        let color = HslColor::new(30, 60, 40);
+        let mut spinner = Spinner::new(color, 5);
+        spinner.with_hue(SpinMode::RelativeIncl(200));
+        spinner.with_lightness(SpinMode::Offset(&[0, 10, -5]));
+
+        let expected: Vec<HslaColor> = (0..5).map(|_| spinner.spin_next()).collect();
+        for (index, expected) in expected.iter().enumerate().rev() {
+            assert_eq!(spinner.spin_at(index), *expected);
+        }
+
+        spinner.seek(3);
+        assert_eq!(spinner.spin_next(), expected[3]);
+
+        let base = HslaColor::new(180, 50, 50, 100);
+        let mut other = Spinner::new(base, 5);
+        other.with_hue(SpinMode::RelativeIncl(200));
+        other.with_lightness(SpinMode::Offset(&[0, 10, -5]));
+        assert_eq!(spinner.spin_at_with_base(base, 2), other.spin_at(2));
+    }
+
+    #[test]
+    fn spinner_single_step_abs() {
+
+        let color = HslColor::new(0, 100, 50);
+        let mut spinner = Spinner::new(color, 1);
+        spinner.with_lightness(SpinMode::Absolute(100));
+        spinner.with_hue(SpinMode::RelativeIncl(90));
+
+        let result = spinner.spin_next();
+        assert_f32_near!(result.h, 0.0, 99999);
+        assert_f32_near!(result.l, 50.0, 99999);
+        assert!(spinner.spin_finished());
+    }
+
+    #[test]
+    fn spinner_abs_follows_new_base() {
+
+        let color = HslColor::new(0, 100, 50);
+        let mut spinner = Spinner::new(color, 3);
+        spinner.with_lightness(SpinMode::Absolute(95));
+
+        spinner.with_color(HslaColor::from(HslColor::new(0, 100, 20)));
+        spinner.seek(1);
+        assert_f32_near!(spinner.spin_next().l, 57.5, 99999);
+        assert_f32_near!(spinner.spin_next().l, 95.0, 99999);
+
+        let base = HslaColor::from(HslColor::new(0, 100, 80));
+        assert_f32_near!(spinner.spin_at_with_base(base, 2).l, 95.0, 99999);
+    }
+
+    #[test]
+    fn spinner_hue_abs_interpolation() {
+
+        let color = HslColor::new(350, 100, 50);
+        let mut spinner = Spinner::new(color, 3);
+        spinner.with_hue(SpinMode::Absolute(10));
+        assert_f32_near!(spinner.spin_at(1).h, 180.0, 99999);
+
+        spinner.with_hue_interpolation(HueInterpolation::Shorter);
+        assert_f32_near!(spinner.spin_at(1).h, 0.0, 99999);
+        assert_f32_near!(spinner.spin_at(2).h, 10.0, 99999);
+
+        spinner.with_hue_interpolation(HueInterpolation::Decreasing);
+        assert_f32_near!(spinner.spin_at(1).h, 180.0, 99999);
+    }
+
+    #[test]
+    fn spinner_gradient_hue_interpolation() {
+
+        let color = HslColor::new(300, 100, 50);
+        let mut spinner = Spinner::new(color, 5);
+        spinner.with_hue_interpolation(HueInterpolation::Increasing);
+        spinner.with_gradient(&[(HslColor::new(20, 100, 50), 0.5), (HslColor::new(340, 100, 50), 1.0)]);
+
+        assert_f32_near!(spinner.spin_at(1).h, 340.0, 99999);
+        assert_f32_near!(spinner.spin_at(2).h, 20.0, 99999);
+        assert_f32_near!(spinner.spin_at(3).h, 180.0, 99999);
+    }
+
+    #[test]
+    fn spinner_zero_count_empty_offsets() {
+
+        let color = HslColor::new(0, 100, 50);
+        let mut spinner = Spinner::new(color, 0);
+        spinner.with_lightness(SpinMode::Absolute(100));
+        spinner.with_hue(SpinMode::Offset(&[]));
+        assert!(spinner.spin_finished());
+
+        let mut spinner = Spinner::new(color, 2);
+        spinner.with_hue(SpinMode::OffsetVec(Vec::new()));
+        let result = spinner.spin_next();
+        assert_f32_near!(result.h, 0.0, 99999);
+    }
+
+    #[test]
+    fn spinner_oklch_lit_abs() {
+
+        let color = HslColor::new(240, 100, 50);
+        let mut spinner = Spinner::new(color, 3);
+        spinner.with_space(SpinSpace::Oklch);
+        spinner.with_lightness(SpinMode::Absolute(100));
+
+        let result = spinner.spin_next();
+        assert_float_absolute_eq!(result.h, 240.0, 0.1);
+        assert_float_absolute_eq!(result.l, 50.0, 0.1);
+
+        let result = spinner.spin_next();
+        assert_gt!(result.l, 50.0);
+        assert_lt!(result.l, 100.0);
+
+        let result = spinner.spin_next();
+        assert_float_absolute_eq!(result.l, 100.0, 0.1);
+    }
+
+    #[test]
+    fn spinner_oklch_keeps_perceived_lightness() {
+
+        let color = HslColor::new(0, 100, 50);
+        let mut spinner = Spinner::new(color, 4);
+        spinner.with_space(SpinSpace::Oklch);
+        spinner.with_hue(SpinMode::RelativeExcl(360));
+
+        let first = crate::OklchColor::from(HslColor::from(spinner.spin_next()));
+        for _ in 1..4 {
+            let result = crate::OklchColor::from(HslColor::from(spinner.spin_next()));
+            assert_float_absolute_eq!(result.l, first.l, 0.01);
+        }
+    }
+
 }
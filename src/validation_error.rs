@@ -0,0 +1,86 @@
+use std::fmt;
+use crate::{ HslaColor, SpinMode };
+
+/// Errors of the fallible (`try_`) methods of [`KolorWheel`](crate::KolorWheel)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// Number of spin steps is zero
+    ZeroCount,
+    /// Offset list is empty
+    EmptyOffsets,
+    /// Absolute target of Saturation, Lightness or Alpha
+    /// is out of 0..=100 range,
+    /// reporting the target value
+    TargetOutOfRange(i32),
+    /// Color channel or offset is not finite: NaN or infinite
+    NonFinite,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::ZeroCount => write!(f, "number of spin steps is zero"),
+            ValidationError::EmptyOffsets => write!(f, "offset list is empty"),
+            ValidationError::TargetOutOfRange(target) => {
+                write!(f, "absolute target {} is out of 0..=100 range", target)
+            },
+            ValidationError::NonFinite => write!(f, "color channel or offset is not finite"),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+pub(crate) fn validate_count(count: usize) -> Result<(), ValidationError> {
+    match count {
+        0 => Err(ValidationError::ZeroCount),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn validate_color(color: HslaColor) -> Result<(), ValidationError> {
+    if [color.h, color.s, color.l, color.a].iter().any(|channel| !channel.is_finite()) {
+        return Err(ValidationError::NonFinite);
+    }
+    Ok(())
+}
+
+/// Check spin mode, `is_percent` is set for
+/// Saturation, Lightness and Alpha channels
+pub(crate) fn validate_spin_mode(spin_mode: &SpinMode, is_percent: bool) -> Result<(), ValidationError> {
+
+    match spin_mode {
+        SpinMode::Absolute(target) if is_percent && !(0..=100).contains(target) => {
+            Err(ValidationError::TargetOutOfRange(*target))
+        },
+        SpinMode::Offset([]) => Err(ValidationError::EmptyOffsets),
+        SpinMode::OffsetVec(values) if values.is_empty() => Err(ValidationError::EmptyOffsets),
+        SpinMode::OffsetVec(values) if values.iter().any(|value| !value.is_finite()) => {
+            Err(ValidationError::NonFinite)
+        },
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validation_error_message() {
+        let message = ValidationError::TargetOutOfRange(120).to_string();
+        assert_eq!(message, "absolute target 120 is out of 0..=100 range");
+    }
+
+    #[test]
+    fn validation_spin_modes() {
+        assert_eq!(validate_spin_mode(&SpinMode::Absolute(120), false), Ok(()));
+        assert_eq!(validate_spin_mode(&SpinMode::Absolute(-1), true), Err(ValidationError::TargetOutOfRange(-1)));
+        assert_eq!(validate_spin_mode(&SpinMode::RelativeIncl(200), true), Ok(()));
+        assert_eq!(validate_spin_mode(&SpinMode::offsets_f32([1.0, f32::NAN]), false), Err(ValidationError::NonFinite));
+        assert_eq!(validate_spin_mode(&SpinMode::offsets_f32([f32::INFINITY]), true), Err(ValidationError::NonFinite));
+        assert_eq!(validate_spin_mode(&SpinMode::offsets_f32([f32::NEG_INFINITY]), false), Err(ValidationError::NonFinite));
+    }
+
+}
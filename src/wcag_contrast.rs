@@ -0,0 +1,316 @@
+use crate::hsl_color::HslColor;
+use crate::rgb_color::RgbColor;
+use crate::linear_rgb::srgb_to_linear;
+
+/// WCAG 2.x conformance levels of contrast
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[non_exhaustive]
+pub enum WcagLevel {
+    /// Minimum contrast (success criterion 1.4.3)
+    Aa,
+    /// Enhanced contrast (success criterion 1.4.6)
+    Aaa,
+}
+
+/// Text size categories of WCAG 2.x
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum TextSize {
+    /// Regular text
+    Normal,
+    /// Large text: at least 18pt, or 14pt bold
+    Large,
+}
+
+/// What [`KolorWheel::with_min_contrast()`](crate::KolorWheel::with_min_contrast)
+/// emits for colors, which can not reach the ratio by any lightness
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum ContrastFallback {
+    /// Emit the color unchanged
+    #[default]
+    Keep,
+    /// Replace the color with its black or white variant,
+    /// whichever has the higher contrast
+    BlackOrWhite,
+}
+
+impl WcagLevel {
+
+    /// Minimum contrast ratio required by the level for the text size
+    pub fn min_contrast_ratio(self, text_size: TextSize) -> f32 {
+
+        match (self, text_size) {
+            (WcagLevel::Aa, TextSize::Normal) => 4.5,
+            (WcagLevel::Aa, TextSize::Large) => 3.0,
+            (WcagLevel::Aaa, TextSize::Normal) => 7.0,
+            (WcagLevel::Aaa, TextSize::Large) => 4.5,
+        }
+    }
+
+    /// Highest level a contrast ratio meets for the text size, if any
+    pub fn from_contrast_ratio(ratio: f32, text_size: TextSize) -> Option<WcagLevel> {
+
+        if ratio >= WcagLevel::Aaa.min_contrast_ratio(text_size) {
+            Some(WcagLevel::Aaa)
+        } else if ratio >= WcagLevel::Aa.min_contrast_ratio(text_size) {
+            Some(WcagLevel::Aa)
+        } else {
+            None
+        }
+    }
+
+}
+
+pub(crate) fn relative_luminance_f32([r, g, b]: [f32; 3]) -> f32 {
+    0.2126 * srgb_to_linear(r) + 0.7152 * srgb_to_linear(g) + 0.0722 * srgb_to_linear(b)
+}
+
+fn contrast_ratio_of_luminances(a: f32, b: f32) -> f32 {
+    let lighter = a.max(b);
+    let darker = a.min(b);
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+impl RgbColor {
+
+    /// Relative luminance as defined by WCAG 2.x, 0..=1
+    pub fn relative_luminance(&self) -> f32 {
+        let r = f32::from(self.r) / 255.0;
+        let g = f32::from(self.g) / 255.0;
+        let b = f32::from(self.b) / 255.0;
+        relative_luminance_f32([r, g, b])
+    }
+
+    /// WCAG 2.x contrast ratio against other color, 1..=21,
+    /// order of the colors does not matter
+    pub fn contrast_ratio<T>(&self, other: T) -> f32 
+    where T: Into<RgbColor> {
+        let other: RgbColor = other.into();
+        contrast_ratio_of_luminances(self.relative_luminance(), other.relative_luminance())
+    }
+
+    /// Check whether the pair meets the WCAG 2.x level for the text size
+    pub fn meets_wcag<T>(&self, other: T, level: WcagLevel, text_size: TextSize) -> bool 
+    where T: Into<RgbColor> {
+        self.contrast_ratio(other) >= level.min_contrast_ratio(text_size)
+    }
+
+    /// Highest WCAG 2.x level the pair meets for the text size, if any
+    pub fn wcag_level<T>(&self, other: T, text_size: TextSize) -> Option<WcagLevel> 
+    where T: Into<RgbColor> {
+        WcagLevel::from_contrast_ratio(self.contrast_ratio(other), text_size)
+    }
+
+}
+
+impl HslColor {
+
+    /// Relative luminance as defined by WCAG 2.x, 0..=1
+    pub fn relative_luminance(&self) -> f32 {
+        relative_luminance_f32(self.convert_hsl_to_rgb_f32())
+    }
+
+    /// WCAG 2.x contrast ratio against other color, 1..=21,
+    /// order of the colors does not matter
+    pub fn contrast_ratio<T>(&self, other: T) -> f32 
+    where T: Into<HslColor> {
+        let other: HslColor = other.into();
+        contrast_ratio_of_luminances(self.relative_luminance(), other.relative_luminance())
+    }
+
+    /// Check whether the pair meets the WCAG 2.x level for the text size
+    pub fn meets_wcag<T>(&self, other: T, level: WcagLevel, text_size: TextSize) -> bool 
+    where T: Into<HslColor> {
+        self.contrast_ratio(other) >= level.min_contrast_ratio(text_size)
+    }
+
+    /// Highest WCAG 2.x level the pair meets for the text size, if any
+    pub fn wcag_level<T>(&self, other: T, text_size: TextSize) -> Option<WcagLevel> 
+    where T: Into<HslColor> {
+        WcagLevel::from_contrast_ratio(self.contrast_ratio(other), text_size)
+    }
+
+    /// Find the nearest lightness, keeping hue and saturation,
+    /// which reaches the minimum contrast ratio against the background;
+    /// the ratio is checked on the 8-bit [`RgbColor`](RgbColor) of both colors,
+    /// so it holds after conversion;
+    /// returns `None` if no lightness can reach it
+    pub fn with_min_contrast<T>(&self, background: T, min_ratio: f32) -> Option<HslColor> 
+    where T: Into<HslColor> {
+
+        let background = RgbColor::from(background.into());
+        let background_luminance = background.relative_luminance();
+        let meets = |color: HslColor| RgbColor::from(color).contrast_ratio(background) >= min_ratio;
+
+        if meets(*self) {
+            return Some(*self);
+        }
+
+        let lighter = self.search_lightness(self.l, 100.0, |color| {
+            meets(color) && RgbColor::from(color).relative_luminance() >= background_luminance
+        });
+        let darker = self.search_lightness(self.l, 0.0, |color| {
+            meets(color) && RgbColor::from(color).relative_luminance() <= background_luminance
+        });
+
+        match (lighter, darker) {
+            (Some(lighter), Some(darker)) => {
+                if (lighter.l - self.l).abs() <= (self.l - darker.l).abs() {
+                    Some(lighter)
+                } else {
+                    Some(darker)
+                }
+            },
+            (lighter, darker) => lighter.or(darker),
+        }
+    }
+
+    /// Bisect lightness between `from` (failing) and `to`,
+    /// the quantized luminance is monotonic in lightness,
+    /// the result is always on the passing side
+    fn search_lightness<F>(&self, from: f32, to: f32, meets: F) -> Option<HslColor> 
+    where F: Fn(HslColor) -> bool {
+
+        let with_lightness = |l: f32| HslColor { l, ..*self };
+
+        if !meets(with_lightness(to)) {
+            return None;
+        }
+
+        let mut failing = from;
+        let mut passing = to;
+        for _ in 0..24 {
+            let middle = (failing + passing) / 2.0;
+            if meets(with_lightness(middle)) {
+                passing = middle;
+            } else {
+                failing = middle;
+            }
+        }
+
+        Some(with_lightness(passing))
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_float_eq::*;
+
+    const WHITE: RgbColor = RgbColor { r: 255, g: 255, b: 255 };
+    const BLACK: RgbColor = RgbColor { r: 0, g: 0, b: 0 };
+
+    #[test]
+    fn wcag_luminance_extremes() {
+        assert_float_absolute_eq!(WHITE.relative_luminance(), 1.0, 1e-5);
+        assert_float_absolute_eq!(BLACK.relative_luminance(), 0.0, 1e-5);
+    }
+
+    #[test]
+    fn wcag_luminance_primaries() {
+        assert_float_absolute_eq!(RgbColor { r: 255, g: 0, b: 0 }.relative_luminance(), 0.2126, 1e-4);
+        assert_float_absolute_eq!(RgbColor { r: 0, g: 255, b: 0 }.relative_luminance(), 0.7152, 1e-4);
+        assert_float_absolute_eq!(RgbColor { r: 0, g: 0, b: 255 }.relative_luminance(), 0.0722, 1e-4);
+    }
+
+    #[test]
+    fn wcag_ratio_black_white() {
+        assert_float_absolute_eq!(WHITE.contrast_ratio(BLACK), 21.0, 1e-3);
+        assert_float_absolute_eq!(BLACK.contrast_ratio(WHITE), 21.0, 1e-3);
+    }
+
+    #[test]
+    fn wcag_ratio_same_color() {
+        let color = RgbColor { r: 30, g: 144, b: 200 };
+        assert_float_absolute_eq!(color.contrast_ratio(color), 1.0, 1e-5);
+    }
+
+    #[test]
+    fn wcag_ratio_reference_grays() {
+        let gray_aa = RgbColor { r: 0x76, g: 0x76, b: 0x76 };
+        let gray_fail = RgbColor { r: 0x77, g: 0x77, b: 0x77 };
+        let gray_aaa = RgbColor { r: 0x59, g: 0x59, b: 0x59 };
+        assert_float_absolute_eq!(gray_aa.contrast_ratio(WHITE), 4.54, 0.01);
+        assert_float_absolute_eq!(gray_fail.contrast_ratio(WHITE), 4.48, 0.01);
+        assert_float_absolute_eq!(gray_aaa.contrast_ratio(WHITE), 7.0, 0.01);
+    }
+
+    #[test]
+    fn wcag_classify() {
+        let gray_aa = RgbColor { r: 0x76, g: 0x76, b: 0x76 };
+        let gray_fail = RgbColor { r: 0x77, g: 0x77, b: 0x77 };
+        assert!(gray_aa.meets_wcag(WHITE, WcagLevel::Aa, TextSize::Normal));
+        assert!(!gray_fail.meets_wcag(WHITE, WcagLevel::Aa, TextSize::Normal));
+        assert!(!gray_fail.meets_wcag(WHITE, WcagLevel::Aaa, TextSize::Large));
+        assert_eq!(gray_fail.wcag_level(WHITE, TextSize::Large), Some(WcagLevel::Aa));
+        assert_eq!(BLACK.wcag_level(WHITE, TextSize::Normal), Some(WcagLevel::Aaa));
+        assert_eq!(WHITE.wcag_level(WHITE, TextSize::Large), None);
+    }
+
+    #[test]
+    fn wcag_min_contrast_already_met() {
+        let color = HslColor::new(0, 0, 0);
+        let background = HslColor::new(0, 0, 100);
+        let result = color.with_min_contrast(background, 4.5);
+        assert_eq!(result, Some(color));
+        assert!(RgbColor::from(color).contrast_ratio(RgbColor::from(background)) >= 4.5);
+    }
+
+    #[test]
+    fn wcag_min_contrast_darken() {
+        let color = HslColor::new(220, 80, 60);
+        let background = HslColor::new(0, 0, 100);
+        let result = color.with_min_contrast(background, 4.5).unwrap();
+        assert_f32_near!(result.h, 220.0);
+        assert_f32_near!(result.s, 80.0);
+        assert!(result.l < 60.0);
+        let ratio = RgbColor::from(result).contrast_ratio(RgbColor::from(background));
+        assert!(ratio >= 4.5);
+        assert_float_absolute_eq!(ratio, 4.5, 0.1);
+    }
+
+    #[test]
+    fn wcag_min_contrast_lighten() {
+        let color = HslColor::new(120, 50, 30);
+        let background = HslColor::new(0, 0, 10);
+        let result = color.with_min_contrast(background, 7.0).unwrap();
+        assert!(result.l > 30.0);
+        let ratio = RgbColor::from(result).contrast_ratio(RgbColor::from(background));
+        assert!(ratio >= 7.0);
+        assert_float_absolute_eq!(ratio, 7.0, 0.1);
+    }
+
+    #[test]
+    fn wcag_min_contrast_quantized() {
+        let background = HslColor::new(0, 0, 10);
+        let result = HslColor::new(0, 0, 0).with_min_contrast(background, 7.0).unwrap();
+        assert!(RgbColor::from(result).contrast_ratio(RgbColor::from(background)) >= 7.0);
+    }
+
+    #[test]
+    fn wcag_min_contrast_nearest() {
+        let color = HslColor::new(0, 0, 45);
+        let background = HslColor::new(0, 0, 45);
+        let result = color.with_min_contrast(background, 2.0).unwrap();
+        assert!(result.l < 45.0);
+        assert!(RgbColor::from(result).contrast_ratio(RgbColor::from(background)) >= 2.0);
+    }
+
+    #[test]
+    fn wcag_min_contrast_impossible() {
+        let color = HslColor::new(0, 100, 50);
+        let background = HslColor::new(0, 0, 50);
+        assert_eq!(color.with_min_contrast(background, 7.0), None);
+    }
+
+    #[test]
+    fn wcag_hsl() {
+        let text = HslColor::new(0, 0, 0);
+        let background = HslColor::new(60, 100, 50);
+        assert_float_absolute_eq!(text.contrast_ratio(background), 19.56, 0.01);
+        assert_eq!(text.wcag_level(background, TextSize::Normal), Some(WcagLevel::Aaa));
+    }
+
+}